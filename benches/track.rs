@@ -40,7 +40,7 @@ fn dot_product_scalar(l: &[f32], r: &[f32]) -> f32 {
 
 fn benchmark(c: &mut Criterion) {
     let vecs = iter::repeat_with(rand::random)
-        .map(|v: [f32; V::LANES]| V::new(&v))
+        .map(|v: [f32; V::LANES]| V::new(v))
         .take(SIZE / V::LANES)
         .collect::<Vec<_>>();
 
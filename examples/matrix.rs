@@ -43,8 +43,8 @@ impl Matrix {
         let offsets = (0..L).collect::<Vec<_>>();
         let base_offsets = O::new(offsets) * SIZE;
         let mut offsets: [O; SIZE / L] = [Default::default(); SIZE / L];
-        for i in 0..SIZE / L {
-            offsets[i] = base_offsets + i * L * SIZE;
+        for (i, off) in offsets.iter_mut().enumerate() {
+            *off = base_offsets + i * L * SIZE;
         }
 
         // Across columns
@@ -0,0 +1,29 @@
+//! Build test of slipstream in a `no_std` crate.
+//!
+//! There's nothing to run here, it only needs to compile for a target without the standard
+//! library.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use slipstream::prelude::*;
+
+/// Computes a dot product of two slices.
+pub fn dot_product(l: &[f32], r: &[f32]) -> f32 {
+    (l, r)
+        .vectorize_pad((f32x8::default(), f32x8::default()))
+        .map(|(l, r)| l * r)
+        .sum::<f32x8>()
+        .horizontal_sum()
+}
+
+/// Doubles all the values of an owned vector.
+#[cfg(feature = "alloc")]
+pub fn double(data: alloc::vec::Vec<u32>) -> alloc::vec::Vec<u32> {
+    let len = data.len();
+    data.vectorize_pad(u32x4::default())
+        .map(|v| v * 2)
+        .collect_scalars(len)
+}
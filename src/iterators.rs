@@ -20,6 +20,10 @@
 //! # double(&[], &mut [])
 //! ```
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
@@ -27,9 +31,19 @@ use core::ops::*;
 use core::ptr;
 use core::slice;
 
-use crate::inner::Repr;
+use crate::inner::{Cast, Repr};
 use crate::vector::align::Align;
-use crate::Vector;
+use crate::vector::Masked;
+use crate::{Mask, Vector};
+
+#[cfg(feature = "rayon")]
+mod par;
+
+#[cfg(feature = "rayon")]
+pub use par::ParVectorizedIter;
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+pub use par::ParVectorizer;
 
 // TODO: Deref to arrays, not slices
 /// A proxy object for iterating over mutable slices.
@@ -86,8 +100,50 @@ where
     }
 }
 
+impl<A: Align, B: Repr, const S: usize> MutProxy<'_, B, Vector<A, B, S>> {
+    /// Overwrites the lanes enabled by the mask with the ones from `values`.
+    ///
+    /// The disabled lanes are kept. This is the in-place variant of [`blend`][Vector::blend],
+    /// useful for updating only some of the elements of a slice without branching.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = [1, -2, 3, -4, 5];
+    /// for mut v in (&mut data).vectorize_pad(i32x4::default()) {
+    ///     let negative = v.lt(i32x4::default());
+    ///     v.update_where(negative, i32x4::default());
+    /// }
+    /// assert_eq!(data, [1, 0, 3, 0, 5]);
+    /// ```
+    #[inline]
+    pub fn update_where<M, MB>(&mut self, mask: M, values: Vector<A, B, S>)
+    where
+        M: AsRef<[MB]>,
+        MB: Mask,
+    {
+        self.data = self.data.blend(values, mask);
+    }
+}
+
+/// Information about how the data are split into vectors.
+///
+/// Returned by [`Vectorizable::create_info`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct VectorizeInfo {
+    /// Number of full vectors (not counting the padding vector).
+    pub full_vectors: usize,
+    /// Number of elements that don't fit into the full vectors.
+    ///
+    /// These end up in the padding vector, if one is requested.
+    pub remainder: usize,
+}
+
 #[doc(hidden)]
 pub trait Partial<V> {
+    /// Is the partial at the front of the sequence (instead of the back)?
+    const FRONT: bool = false;
     fn take_partial(&mut self) -> Option<V>;
     fn size(&self) -> usize;
 }
@@ -113,6 +169,22 @@ impl<V> Partial<V> for Option<V> {
     }
 }
 
+/// A partial placed at the front of the iteration, see [`Vectorizable::vectorize_rev`].
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub struct Front<V>(Option<V>);
+
+impl<V> Partial<V> for Front<V> {
+    const FRONT: bool = true;
+    #[inline]
+    fn take_partial(&mut self) -> Option<V> {
+        self.0.take()
+    }
+    fn size(&self) -> usize {
+        self.0.is_some() as usize
+    }
+}
+
 #[doc(hidden)]
 pub trait Vectorizer<R> {
     /// Get the nth vector.
@@ -127,7 +199,8 @@ pub trait Vectorizer<R> {
 /// The iterator returned by methods on [`Vectorizable`].
 ///
 /// While it's unusual to need to *name* the type, this is the thing that is returned from
-/// [`Vectorizable::vectorize`] and [`Vectorizable::vectorize_pad`]. It might be of interest to
+/// [`Vectorizable::vectorize`], [`Vectorizable::vectorize_pad`] and
+/// [`Vectorizable::vectorize_rev`]. It might be of interest to
 /// know that it implements several iterator „extensions“ ([`DoubleEndedIterator`],
 /// [`ExactSizeIterator`] and [`FusedIterator`]). Also, several methods are optimized ‒ for
 /// example, the `count` is constant time operation, while the generic is linear.
@@ -149,6 +222,11 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<R> {
+        if P::FRONT {
+            if let Some(partial) = self.partial.take_partial() {
+                return Some(partial);
+            }
+        }
         if self.left < self.right {
             let idx = self.left;
             self.left += 1;
@@ -176,10 +254,17 @@ where
         self.next_back()
     }
 
-    // TODO: This wants some tests
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<R> {
+    fn nth(&mut self, mut n: usize) -> Option<R> {
+        if P::FRONT && self.partial.size() > 0 {
+            let partial = self.partial.take_partial();
+            if n == 0 {
+                return partial;
+            }
+            n -= 1;
+        }
         let main_len = self.right - self.left;
+        // Note: if n == main_len, we skip all the main vectors and the next returns the partial
         if main_len >= n {
             self.left += n;
             self.next()
@@ -196,16 +281,20 @@ where
     V: Vectorizer<R>,
     P: Partial<R>,
 {
-    // TODO: Tests
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(partial) = self.partial.take_partial() {
-            Some(partial)
-        } else if self.left < self.right {
+        // The partial is the last one when iterating forward, so it's first from the back (unless
+        // it is placed at the front).
+        if !P::FRONT {
+            if let Some(partial) = self.partial.take_partial() {
+                return Some(partial);
+            }
+        }
+        if self.left < self.right {
             self.right -= 1;
             Some(unsafe { self.vectorizer.get(self.right) })
         } else {
-            None
+            self.partial.take_partial()
         }
     }
 }
@@ -224,6 +313,167 @@ where
 {
 }
 
+/// Masks with only some of the first lanes enabled.
+#[doc(hidden)]
+pub trait LaneMask: Copy {
+    /// Creates a mask with the first `n` lanes set (all of them if `n` is larger).
+    fn first_lanes(n: usize) -> Self;
+}
+
+impl<A: Align, M: Mask + Repr, const S: usize> LaneMask for Vector<A, M, S> {
+    #[inline]
+    fn first_lanes(n: usize) -> Self {
+        let mut result = Self::splat(M::FALSE);
+        for lane in result.iter_mut().take(n) {
+            *lane = M::TRUE;
+        }
+        result
+    }
+}
+
+/// The iterator returned by [`Vectorizable::vectorize_masked`].
+///
+/// Yields the vectors together with masks of the lanes that contain actual data.
+#[derive(Copy, Clone, Debug)]
+pub struct VectorizedMasked<V, R, M> {
+    inner: VectorizedIter<V, Option<R>, R>,
+    full: M,
+    partial: M,
+}
+
+impl<V, R, M> Iterator for VectorizedMasked<V, R, M>
+where
+    V: Vectorizer<R>,
+    M: Copy,
+{
+    type Item = (R, M);
+
+    #[inline]
+    fn next(&mut self) -> Option<(R, M)> {
+        // Once the full vectors run out, we get the partial
+        let mask = if self.inner.left < self.inner.right {
+            self.full
+        } else {
+            self.partial
+        };
+        self.inner.next().map(|v| (v, mask))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V, R, M> DoubleEndedIterator for VectorizedMasked<V, R, M>
+where
+    V: Vectorizer<R>,
+    M: Copy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(R, M)> {
+        // The partial goes first from the back
+        let mask = if self.inner.partial.is_some() {
+            self.partial
+        } else {
+            self.full
+        };
+        self.inner.next_back().map(|v| (v, mask))
+    }
+}
+
+impl<V, R, M> ExactSizeIterator for VectorizedMasked<V, R, M>
+where
+    V: Vectorizer<R>,
+    M: Copy,
+{
+}
+
+impl<V, R, M> FusedIterator for VectorizedMasked<V, R, M>
+where
+    V: Vectorizer<R>,
+    M: Copy,
+{
+}
+
+/// Vectors of element indices.
+#[doc(hidden)]
+pub trait LaneIndices: Copy {
+    /// Creates the indices of the elements in the vector at position `idx`.
+    fn lane_indices(idx: usize) -> Self;
+}
+
+impl<A: Align, B: Repr, const S: usize> LaneIndices for Vector<A, B, S>
+where
+    usize: Cast<B>,
+{
+    #[inline]
+    fn lane_indices(idx: usize) -> Self {
+        let start = idx * S;
+        Self::from_fn(|i| (start + i).cast())
+    }
+}
+
+/// The iterator returned by [`Vectorizable::vectorize_enumerate`].
+///
+/// Yields the vectors together with vectors of the indices of their elements.
+#[derive(Copy, Clone, Debug)]
+pub struct VectorizedEnumerate<V, R, I> {
+    inner: VectorizedIter<V, Option<R>, R>,
+    _indices: PhantomData<I>,
+}
+
+impl<V, R, I> Iterator for VectorizedEnumerate<V, R, I>
+where
+    V: Vectorizer<R>,
+    I: LaneIndices,
+{
+    type Item = (I, R);
+
+    #[inline]
+    fn next(&mut self) -> Option<(I, R)> {
+        // The partial one goes after all the full ones, so its position is the right end.
+        let idx = self.inner.left;
+        self.inner.next().map(|v| (I::lane_indices(idx), v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V, R, I> DoubleEndedIterator for VectorizedEnumerate<V, R, I>
+where
+    V: Vectorizer<R>,
+    I: LaneIndices,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(I, R)> {
+        // The partial goes first from the back
+        let idx = if self.inner.partial.is_some() {
+            self.inner.right
+        } else {
+            self.inner.right.wrapping_sub(1)
+        };
+        self.inner.next_back().map(|v| (I::lane_indices(idx), v))
+    }
+}
+
+impl<V, R, I> ExactSizeIterator for VectorizedEnumerate<V, R, I>
+where
+    V: Vectorizer<R>,
+    I: LaneIndices,
+{
+}
+
+impl<V, R, I> FusedIterator for VectorizedEnumerate<V, R, I>
+where
+    V: Vectorizer<R>,
+    I: LaneIndices,
+{
+}
+
 /// A trait describing things with direct support for splitting into vectors.
 ///
 /// This supports vectorized iteration over shared and mutable slices as well as types composed of
@@ -237,13 +487,20 @@ where
 /// Already pre-vectorized inputs are also supported (this is useful in combination with other not
 /// vectorized inputs).
 ///
+/// With the `alloc` feature, owned [`Vec`]s and boxed slices are supported as
+/// well. These are consumed by the iterator and produce owned vectors, like shared slices.
+///
+/// Shared slices known to be aligned can be wrapped in [`Aligned`] to use aligned loads.
+/// Every n-th element of a shared slice can be vectorized by wrapping it in [`Strided`] (or with
+/// [`vectorize_strided`][crate::vectorize_strided]).
+///
 /// # Type hints
 ///
 /// Oftentimes, the compiler can infer the type of the base type, but not the length of the vector.
 /// It is therefore needed to provide a type hint.
 ///
-/// Furthermore, for tuples and arrays, the inner type really needs to be the slice, not something
-/// that can coerce into it (eg. vec or array).
+/// Furthermore, for tuples and arrays, the inner type really needs to be the slice or a reference
+/// to a fixed-sized array, not something that can coerce into it (eg. vec).
 ///
 /// Alternatively, you can use the free-standing functions [`vectorize`][crate::vectorize] and
 /// [`vectorize_pad`][crate::vectorize_pad]. It allows using the turbofish to provide the hint.
@@ -302,6 +559,69 @@ pub trait Vectorizable<V>: Sized {
     /// Internal method to create the vectorizer and kick of the iteration.
     fn create(self, pad: Option<Self::Padding>) -> (Self::Vectorizer, usize, Option<V>);
 
+    /// Creates the vectorizer, like [`create`][Vectorizable::create], with more details.
+    ///
+    /// In addition to the number of full vectors, this provides the number of elements that
+    /// didn't fit into them (see [`remainder`][Vectorizable::remainder]). This may be useful to
+    /// size a manual scalar loop handling the rest of the data.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// # use slipstream::Vectorizable;
+    /// let data = [1u32, 2, 3, 4, 5, 6];
+    /// let pad = Some(u32x4::default());
+    /// let (_, info, partial) = Vectorizable::<u32x4>::create_info(&data[..], pad);
+    /// assert_eq!(info.full_vectors, 1);
+    /// assert_eq!(info.remainder, 2);
+    /// assert_eq!(partial, Some(u32x4::new([5, 6, 0, 0])));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Under the same conditions as `create` (eg. if there's a remainder, but no `pad`).
+    #[inline]
+    fn create_info(
+        self,
+        pad: Option<Self::Padding>,
+    ) -> (Self::Vectorizer, VectorizeInfo, Option<V>) {
+        let remainder = self.remainder();
+        let (vectorizer, full_vectors, partial) = self.create(pad);
+        let info = VectorizeInfo {
+            full_vectors,
+            remainder,
+        };
+        (vectorizer, info, partial)
+    }
+
+    /// The number of elements that don't fit into full vectors.
+    ///
+    /// For composites, this is the largest remainder of the parts. The default returns 0, which
+    /// is fine for types that never produce a partial vector.
+    #[inline]
+    fn remainder(&self) -> usize {
+        0
+    }
+
+    /// Internal method to create the vectorizer with the partial vector at the front.
+    ///
+    /// The same as [`create`][Vectorizable::create], but the uneven part is taken from the
+    /// beginning of the data and the full vectors are aligned to the end. The default delegates
+    /// to `create`, which is fine for types that never produce a partial vector.
+    #[inline]
+    fn create_front(self, pad: Option<Self::Padding>) -> (Self::Vectorizer, usize, Option<V>) {
+        self.create(pad)
+    }
+
+    /// Internal method to create a padding vector without any data in it.
+    ///
+    /// This is used in composites if some other part needs a padding vector but this one does
+    /// not. Returns `None` if such thing is not supported.
+    #[inline]
+    fn empty_partial(pad: Self::Padding) -> Option<V> {
+        let _ = pad;
+        None
+    }
+
     /// Vectorize a slice or composite of slices
     ///
     /// This variant assumes the input is divisible by the size of the vector. Prefer this if
@@ -349,12 +669,16 @@ pub trait Vectorizable<V>: Sized {
     ///
     /// The padding is produced at the end.
     ///
-    /// In case of composites, this still assumes they produce the same number of full vectors and
-    /// that they all either do or don't need a padding.
+    /// In case of composites, this still assumes they produce the same number of full vectors. If
+    /// some of the parts need a padding vector and some don't (eg. because they are of slightly
+    /// different lengths), the ones that don't get a padding vector with no data in them (the
+    /// whole vector is taken from the `pad` and, for mutable slices, nothing is stored).
     ///
     /// # Panics
     ///
-    /// If the above assumption about number of vectors and same padding behaviour is violated.
+    /// If the above assumption about number of vectors is violated or if some part of the
+    /// composite needs a padding vector and another one can't produce one (eg. already vectorized
+    /// inputs).
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
@@ -373,6 +697,144 @@ pub trait Vectorizable<V>: Sized {
             _result: PhantomData,
         }
     }
+
+    /// Vectorizes a slice or composite of slices into a parallel iterator.
+    ///
+    /// This is the parallel version of [`vectorize`][Vectorizable::vectorize], with the same
+    /// assumptions and panics. The result is an [`IndexedParallelIterator`] from the [`rayon`]
+    /// crate. The padded mode is not supported.
+    ///
+    /// Available with the `rayon` feature.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// use rayon::prelude::*;
+    /// let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let total = data
+    ///     .into_par_vectorize()
+    ///     .map(|v: u32x4| v * v)
+    ///     .sum::<u32x4>()
+    ///     .horizontal_sum();
+    /// assert_eq!(total, 204);
+    /// ```
+    ///
+    /// [`IndexedParallelIterator`]: rayon::iter::IndexedParallelIterator
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn into_par_vectorize(self) -> ParVectorizedIter<Self::Vectorizer, V>
+    where
+        Self::Vectorizer: ParVectorizer<V>,
+        V: Send,
+    {
+        let (vectorizer, len, partial) = self.create(None);
+        assert!(partial.is_none());
+        ParVectorizedIter::new(vectorizer, len)
+    }
+
+    /// Vectorizes a slice or composite of slices, with the padding at the front.
+    ///
+    /// This is similar to [`vectorize_pad`][Vectorizable::vectorize_pad], but the full vectors
+    /// are aligned to the *end* of the data (like with [`rchunks`][slice::rchunks]) and the uneven
+    /// head goes into the padding vector. The iteration order is still forward, so the padding
+    /// vector (if any) comes first, followed by the full vectors. This is different from
+    /// reversing the iterator returned by `vectorize_pad`.
+    ///
+    /// The data of the head are placed into the first lanes of the padding vector, the rest of
+    /// the lanes is taken from the `pad`. For mutable slices, the [`MutProxy`] of the padding
+    /// vector writes back only these first lanes (into the head of the slice) when dropped, the
+    /// added lanes are discarded.
+    ///
+    /// Composites follow the same rules as with `vectorize_pad`.
+    ///
+    /// # Panics
+    ///
+    /// Under the same conditions as [`vectorize_pad`][Vectorizable::vectorize_pad].
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let v = data.vectorize_rev(i32x4::splat(-1)).collect::<Vec<_>>();
+    /// assert_eq!(v, vec![i32x4::new([1, 2, -1, -1]), i32x4::new([3, 4, 5, 6])]);
+    /// ```
+    #[inline(always)]
+    fn vectorize_rev(self, pad: Self::Padding) -> VectorizedIter<Self::Vectorizer, Front<V>, V> {
+        let (vectorizer, len, partial) = self.create_front(Some(pad));
+        VectorizedIter {
+            partial: Front(partial),
+            vectorizer,
+            left: 0,
+            right: len,
+            _result: PhantomData,
+        }
+    }
+
+    /// Vectorizes a slice, pairing each vector with a mask of valid lanes.
+    ///
+    /// This is an alternative to [`vectorize_pad`][Vectorizable::vectorize_pad] when there's no
+    /// good neutral value to pad with. The last (partial) vector has the unused lanes set to the
+    /// default value (zero) and the accompanying mask tells which of the lanes are valid. The masks
+    /// of the full vectors have all the lanes set. The masks can be used with the masked
+    /// operations, like [`blend`][Vector::blend] or
+    /// [`scatter_store_masked`][Vector::scatter_store_masked].
+    ///
+    /// Note that the library doesn't use any special instructions and the partial vector is still
+    /// created by copying the data.
+    ///
+    /// This is available for the types producing vectors (not for composites or mutable slices).
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let v = data.vectorize_masked().collect::<Vec<(i32x4, _)>>();
+    /// assert_eq!(v[0], (i32x4::new([1, 2, 3, 4]), m32x4::splat(m32::TRUE)));
+    /// let mask = m32x4::new([m32::TRUE, m32::TRUE, m32::FALSE, m32::FALSE]);
+    /// assert_eq!(v[1], (i32x4::new([5, 6, 0, 0]), mask));
+    /// ```
+    #[inline]
+    fn vectorize_masked(self) -> VectorizedMasked<Self::Vectorizer, V, V::Mask>
+    where
+        V: Masked,
+        V::Mask: LaneMask,
+        Self::Padding: Default,
+    {
+        let remainder = self.remainder();
+        VectorizedMasked {
+            inner: self.vectorize_pad(Default::default()),
+            full: V::Mask::first_lanes(usize::MAX),
+            partial: V::Mask::first_lanes(remainder),
+        }
+    }
+
+    /// Vectorizes with padding, pairing each vector with a vector of its element indices.
+    ///
+    /// The indices are the positions of the elements (lanes) in the original input, so the first
+    /// vector gets `[0, 1, 2, ...]`, the second one continues where the first one ended, etc. The
+    /// padding lanes of the last vector get indices past the end of the input. The type of the
+    /// index vector is chosen by the caller and it should have the same number of lanes as the
+    /// data vectors.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let v = data
+    ///     .vectorize_enumerate(f32x4::default())
+    ///     .collect::<Vec<(u32x4, f32x4)>>();
+    /// assert_eq!(v[0].0, u32x4::new([0, 1, 2, 3]));
+    /// assert_eq!(v[1].0, u32x4::new([4, 5, 6, 7]));
+    /// ```
+    #[inline]
+    fn vectorize_enumerate<I>(
+        self,
+        pad: Self::Padding,
+    ) -> VectorizedEnumerate<Self::Vectorizer, V, I>
+    where
+        I: LaneIndices,
+    {
+        VectorizedEnumerate {
+            inner: self.vectorize_pad(pad),
+            _indices: PhantomData,
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -388,7 +850,7 @@ pub struct ReadVectorizer<'a, A: Align, B: Repr, const S: usize> {
 unsafe impl<A: Align, B: Repr, const S: usize> Send for ReadVectorizer<'_, A, B, S> {}
 unsafe impl<A: Align, B: Repr, const S: usize> Sync for ReadVectorizer<'_, A, B, S> {}
 
-impl<'a, A: Align, B: Repr, const S: usize> Vectorizer<Vector<A, B, S>>
+impl<A: Align, B: Repr, const S: usize> Vectorizer<Vector<A, B, S>>
     for ReadVectorizer<'_, A, B, S>
 {
     #[inline(always)]
@@ -407,7 +869,7 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<Vector<A, B, S>> for &'
     ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
         let len = self.len();
         assert!(
-            len * mem::size_of::<B>() <= isize::MAX as usize,
+            mem::size_of_val(self) <= isize::MAX as usize,
             "Slice too huge"
         );
         let rest = len % S;
@@ -431,10 +893,31 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<Vector<A, B, S>> for &'
         };
         (me, main / S, partial)
     }
+
+    #[inline]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        let (head, tail) = self.split_at(self.len() % S);
+        let (me, len, _) = Vectorizable::<Vector<A, B, S>>::create(tail, None);
+        let (_, _, partial) = Vectorizable::<Vector<A, B, S>>::create(head, pad);
+        (me, len, partial)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        self.len() % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<Vector<A, B, S>> {
+        Some(pad)
+    }
 }
 
 #[doc(hidden)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Debug)]
 pub struct WriteVectorizer<'a, A: Align, B: Repr, const S: usize> {
     start: *mut B,
     _vector: PhantomData<Vector<A, B, S>>,
@@ -479,7 +962,7 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<MutProxy<'a, B, Vector<
     ) {
         let len = self.len();
         assert!(
-            len * mem::size_of::<B>() <= isize::MAX as usize,
+            mem::size_of_val(self) <= isize::MAX as usize,
             "Slice too huge"
         );
         let rest = len % S;
@@ -488,6 +971,8 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<MutProxy<'a, B, Vector<
         let partial = match (rest, pad) {
             (0, _) => None,
             (_, Some(mut pad)) => {
+                // The proxy writes back only the restore part, the rest of the lanes are dropped
+                debug_assert!(rest < S);
                 let restore = &mut self[main..];
                 pad[..rest].copy_from_slice(restore);
                 Some(MutProxy { data: pad, restore })
@@ -504,58 +989,548 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<MutProxy<'a, B, Vector<
         };
         (me, main / S, partial)
     }
-}
 
-macro_rules! vectorizable_tuple {
-    ($(($X: ident, $XR: ident, $X0: tt)),*) => {
-        impl<$($X, $XR),*> Vectorizer<($($XR),*)> for ($($X),*)
-        where
-            $($X: Vectorizer<$XR>,)*
-        {
-            #[inline(always)]
-            unsafe fn get(&mut self, idx: usize) -> ($($XR),*) {
-                ($(self.$X0.get(idx)),*)
-            }
-        }
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (
+        Self::Vectorizer,
+        usize,
+        Option<MutProxy<'a, B, Vector<A, B, S>>>,
+    ) {
+        let rest = self.len() % S;
+        let (head, tail) = self.split_at_mut(rest);
+        let (me, len, _) = Vectorizable::<MutProxy<'a, B, Vector<A, B, S>>>::create(tail, None);
+        // The head is shorter than a vector, so this produces just the partial
+        let (_, _, partial) = Vectorizable::<MutProxy<'a, B, Vector<A, B, S>>>::create(head, pad);
+        (me, len, partial)
+    }
 
-        impl<$($X, $XR),*> Vectorizable<($($XR),*)> for ($($X),*)
-        where
-            $($X: Vectorizable<$XR>,)*
-        {
-            type Vectorizer = ($($X::Vectorizer),*);
-            type Padding = ($($X::Padding),*);
-            #[inline]
-            #[allow(clippy::eq_op)]
-            fn create(self, pad: Option<Self::Padding>)
-                -> (Self::Vectorizer, usize, Option<($($XR),*)>)
-            {
-                let pad = match pad {
-                    Some(pad) => ($(Some(pad.$X0)),*),
-                    None => Default::default(), // Bunch of Nones in a tuple.. (None, None, None)...
+    #[inline]
+    fn remainder(&self) -> usize {
+        self.len() % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<MutProxy<'a, B, Vector<A, B, S>>> {
+        Some(MutProxy {
+            data: pad,
+            restore: &mut [],
+        })
+    }
+}
+
+/// A slice checked to be aligned for loading vectors directly from it.
+///
+/// The usual vectorization of slices can't assume anything about the alignment of the data and
+/// therefore loads the vectors in a way that works with any alignment. On some (older) CPUs, the
+/// unaligned loads are slower. If the slice is known to be aligned, wrapping it in this type
+/// allows vectorizing it with aligned loads. The alignment is checked when creating the wrapper.
+///
+/// Only the start of the slice is required to be aligned. The uneven end is handled as usual.
+///
+/// Putting the padding at the front (eg. with [`vectorize_rev`][Vectorizable::vectorize_rev])
+/// would shift the full vectors away from the aligned start. Therefore it panics if the slice
+/// contains both full vectors and an uneven part.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// # use slipstream::iterators::Aligned;
+/// let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+/// // A slice backed by the vectors must be aligned
+/// let data: &[u32] = &vectors[0][..];
+/// let aligned = Aligned::<u32x4>::new(data);
+/// let v = aligned.vectorize().collect::<Vec<u32x4>>();
+/// assert_eq!(v, vec![vectors[0]]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Aligned<'a, V> {
+    start: *const V,
+    // Number of the scalar elements, not vectors
+    len: usize,
+    _slice: PhantomData<&'a [V]>,
+}
+
+// Note: This is just a shared slice in disguise.
+unsafe impl<V: Sync> Send for Aligned<'_, V> {}
+unsafe impl<V: Sync> Sync for Aligned<'_, V> {}
+
+impl<'a, A: Align, B: Repr, const S: usize> Aligned<'a, Vector<A, B, S>> {
+    /// Wraps the slice, checking its alignment.
+    ///
+    /// # Panics
+    ///
+    /// If the start of the slice is not aligned as the vector requires. An empty slice is accepted
+    /// with any alignment.
+    pub fn new(data: &'a [B]) -> Self {
+        Vector::<A, B, S>::assert_size();
+        // The dangling pointer of an empty slice is aligned only for B, so use one aligned for the
+        // vector instead. It is never dereferenced anyway.
+        let start = if data.is_empty() {
+            ptr::NonNull::dangling().as_ptr()
+        } else {
+            assert_eq!(
+                data.as_ptr() as usize % mem::align_of::<Vector<A, B, S>>(),
+                0,
+                "Slice not aligned for the vector"
+            );
+            data.as_ptr().cast()
+        };
+        Self {
+            start,
+            len: data.len(),
+            _slice: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped slice.
+    pub fn as_slice(&self) -> &'a [B] {
+        unsafe { slice::from_raw_parts(self.start.cast(), self.len) }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub struct AlignedVectorizer<'a, V> {
+    start: *const V,
+    _slice: PhantomData<&'a [V]>,
+}
+
+// Note: The same as with ReadVectorizer.
+unsafe impl<V: Sync> Send for AlignedVectorizer<'_, V> {}
+unsafe impl<V: Sync> Sync for AlignedVectorizer<'_, V> {}
+
+impl<A: Align, B: Repr, const S: usize> Vectorizer<Vector<A, B, S>>
+    for AlignedVectorizer<'_, Vector<A, B, S>>
+{
+    #[inline(always)]
+    unsafe fn get(&mut self, idx: usize) -> Vector<A, B, S> {
+        // Vector::new_unchecked would do an unaligned load, so we go through the pointer to the
+        // vector. That is aligned for the first one and there's no padding between the vectors
+        // in the slice (checked on creation), so for the others too.
+        *self.start.add(idx)
+    }
+}
+
+impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<Vector<A, B, S>>
+    for Aligned<'a, Vector<A, B, S>>
+{
+    type Vectorizer = AlignedVectorizer<'a, Vector<A, B, S>>;
+    type Padding = Vector<A, B, S>;
+    #[inline]
+    fn create(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        let (_, len, partial) = Vectorizable::<Vector<A, B, S>>::create(self.as_slice(), pad);
+        let me = AlignedVectorizer {
+            start: self.start,
+            _slice: PhantomData,
+        };
+        (me, len, partial)
+    }
+
+    #[inline]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        // The full vectors would start after the head, so they are aligned only if there's no
+        // head or no full vectors at all.
+        assert!(
+            self.len.is_multiple_of(S) || self.len < S,
+            "Aligned data can't have the partial vector at the front"
+        );
+        let (_, len, partial) = Vectorizable::<Vector<A, B, S>>::create_front(self.as_slice(), pad);
+        let me = AlignedVectorizer {
+            start: self.start,
+            _slice: PhantomData,
+        };
+        (me, len, partial)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        self.len % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<Vector<A, B, S>> {
+        Some(pad)
+    }
+}
+
+/// A slice vectorized with a stride, taking only every `stride`-th element.
+///
+/// This is useful for data stored with a stride, like one field of an array of structs. The
+/// elements are taken from the slice the same as with [`step_by`][Iterator::step_by] ‒ the
+/// first element and then every `stride`-th one after it. The last element doesn't need to be
+/// followed by a full stride, so a slice of length `len` produces `len / stride` rounded up
+/// elements. If these don't split into full vectors, the padding is used as with normal slices.
+///
+/// The vectors are gathered element by element, so this is slower than vectorizing continuous
+/// data.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// # use slipstream::iterators::Strided;
+/// // x and y coordinates, interleaved
+/// let data = [1, 10, 2, 20, 3, 30, 4, 40, 5, 50];
+/// let xs = Strided::new(&data[..], 2)
+///     .vectorize_pad(u32x4::default())
+///     .collect::<Vec<_>>();
+/// assert_eq!(xs, vec![u32x4::new([1, 2, 3, 4]), u32x4::new([5, 0, 0, 0])]);
+/// let ys = Strided::new(&data[1..], 2)
+///     .vectorize_pad(u32x4::default())
+///     .collect::<Vec<_>>();
+/// assert_eq!(ys, vec![u32x4::new([10, 20, 30, 40]), u32x4::new([50, 0, 0, 0])]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Strided<'a, B> {
+    data: &'a [B],
+    stride: usize,
+}
+
+impl<'a, B> Strided<'a, B> {
+    /// Wraps the slice, taking every `stride`-th element.
+    ///
+    /// # Panics
+    ///
+    /// If the `stride` is 0.
+    pub fn new(data: &'a [B], stride: usize) -> Self {
+        assert!(stride > 0, "Stride must not be 0");
+        Self { data, stride }
+    }
+
+    /// The number of elements that'll be taken from the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len().div_ceil(self.stride)
+    }
+
+    /// Checks if there are no elements to take.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub struct StridedVectorizer<'a, A: Align, B: Repr, const S: usize> {
+    start: *const B,
+    stride: usize,
+    _vector: PhantomData<Vector<A, B, S>>,
+    _slice: PhantomData<&'a [B]>, // To hold the lifetime
+}
+
+// Note: The same as with ReadVectorizer.
+unsafe impl<A: Align, B: Repr, const S: usize> Send for StridedVectorizer<'_, A, B, S> {}
+unsafe impl<A: Align, B: Repr, const S: usize> Sync for StridedVectorizer<'_, A, B, S> {}
+
+impl<A: Align, B: Repr, const S: usize> Vectorizer<Vector<A, B, S>>
+    for StridedVectorizer<'_, A, B, S>
+{
+    #[inline(always)]
+    unsafe fn get(&mut self, idx: usize) -> Vector<A, B, S> {
+        let start = self.start.add(S * idx * self.stride);
+        Vector::from_fn(|i| *start.add(i * self.stride))
+    }
+}
+
+impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<Vector<A, B, S>> for Strided<'a, B> {
+    type Vectorizer = StridedVectorizer<'a, A, B, S>;
+    type Padding = Vector<A, B, S>;
+    #[inline]
+    fn create(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        let len = self.len();
+        let rest = len % S;
+        let main = len - rest;
+        let partial = match (rest, pad) {
+            (0, _) => None,
+            (_, Some(mut pad)) => {
+                let tail = self.data[main * self.stride..].iter().step_by(self.stride);
+                for (lane, val) in pad.iter_mut().zip(tail) {
+                    *lane = *val;
+                }
+                Some(pad)
+            }
+            _ => panic!(
+                "Data to vectorize not divisible by lanes ({} vs {})",
+                S, len,
+            ),
+        };
+        let me = StridedVectorizer {
+            start: self.data.as_ptr(),
+            stride: self.stride,
+            _vector: PhantomData,
+            _slice: PhantomData,
+        };
+        (me, main / S, partial)
+    }
+
+    #[inline]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        // If everything fits into the head, the slice may end before the last full stride.
+        let mid = (self.len() % S * self.stride).min(self.data.len());
+        let (head, tail) = self.data.split_at(mid);
+        let (me, len, _) =
+            Vectorizable::<Vector<A, B, S>>::create(Self::new(tail, self.stride), None);
+        let (_, _, partial) =
+            Vectorizable::<Vector<A, B, S>>::create(Self::new(head, self.stride), pad);
+        (me, len, partial)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        self.len() % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<Vector<A, B, S>> {
+        Some(pad)
+    }
+}
+
+/// Arrays of lengths not divisible by the number of lanes panic at runtime in the non-padded
+/// mode, as the trait methods are shared with the padded mode. Use
+/// [`vectorize_array`][crate::vectorize_array] for a compile time check.
+impl<'a, A: Align, B: Repr, const S: usize, const N: usize> Vectorizable<Vector<A, B, S>>
+    for &'a [B; N]
+{
+    type Vectorizer = ReadVectorizer<'a, A, B, S>;
+    type Padding = Vector<A, B, S>;
+    #[inline]
+    fn create(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        // The length is known at compile time, so the handling of the partial is optimized out
+        // if N is divisible by S.
+        Vectorizable::<Vector<A, B, S>>::create(&self[..], pad)
+    }
+
+    #[inline]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+        Vectorizable::<Vector<A, B, S>>::create_front(&self[..], pad)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        N % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<Vector<A, B, S>> {
+        Some(pad)
+    }
+}
+
+/// See the shared array version. The compile time checked variant is
+/// [`vectorize_array_mut`][crate::vectorize_array_mut].
+impl<'a, A: Align, B: Repr, const S: usize, const N: usize>
+    Vectorizable<MutProxy<'a, B, Vector<A, B, S>>> for &'a mut [B; N]
+{
+    type Vectorizer = WriteVectorizer<'a, A, B, S>;
+    type Padding = Vector<A, B, S>;
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn create(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (
+        Self::Vectorizer,
+        usize,
+        Option<MutProxy<'a, B, Vector<A, B, S>>>,
+    ) {
+        Vectorizable::<MutProxy<'a, B, Vector<A, B, S>>>::create(&mut self[..], pad)
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn create_front(
+        self,
+        pad: Option<Vector<A, B, S>>,
+    ) -> (
+        Self::Vectorizer,
+        usize,
+        Option<MutProxy<'a, B, Vector<A, B, S>>>,
+    ) {
+        Vectorizable::<MutProxy<'a, B, Vector<A, B, S>>>::create_front(&mut self[..], pad)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        N % S
+    }
+
+    #[inline]
+    fn empty_partial(pad: Vector<A, B, S>) -> Option<MutProxy<'a, B, Vector<A, B, S>>> {
+        <&'a mut [B] as Vectorizable<_>>::empty_partial(pad)
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct OwnedVectorizer<C, A: Align, B: Repr, const S: usize> {
+    data: C,
+    offset: usize,
+    _vector: PhantomData<Vector<A, B, S>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, A, B, const S: usize> Vectorizer<Vector<A, B, S>> for OwnedVectorizer<C, A, B, S>
+where
+    C: AsRef<[B]>,
+    A: Align,
+    B: Repr,
+{
+    #[inline(always)]
+    unsafe fn get(&mut self, idx: usize) -> Vector<A, B, S> {
+        Vector::new_unchecked(self.data.as_ref().as_ptr().add(self.offset + S * idx))
+    }
+}
+
+macro_rules! vectorizable_owned {
+    ($ty: ty) => {
+        #[cfg(feature = "alloc")]
+        impl<A: Align, B: Repr, const S: usize> Vectorizable<Vector<A, B, S>> for $ty {
+            type Vectorizer = OwnedVectorizer<$ty, A, B, S>;
+            type Padding = Vector<A, B, S>;
+            #[inline]
+            fn create(
+                self,
+                pad: Option<Vector<A, B, S>>,
+            ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+                let (_, len, partial) = Vectorizable::<Vector<A, B, S>>::create(&self[..], pad);
+                let me = OwnedVectorizer {
+                    data: self,
+                    offset: 0,
+                    _vector: PhantomData,
                 };
-                let created = ($(self.$X0.create(pad.$X0)),*);
-                $(
-                    // TODO: We may want to support this in the padded mode eventually by
-                    // creating more paddings
-                    assert_eq!(
-                        (created.0).1,
-                        created.$X0.1,
-                        "Vectorizing data of different lengths"
-                    );
-                    // TODO: We could also handle this in the padded mode by doing empty pads
-                    assert_eq!(
-                        (created.0).2.is_some(),
-                        created.$X0.2.is_some(),
-                        "Paddings are not the same for all vectorized data",
-                    );
-                )*
-                let vectorizer = ($(created.$X0.0),*);
-                let pad = if (created.0).2.is_some() {
-                    Some(($(created.$X0.2.unwrap()),*))
-                } else {
-                    None
+                (me, len, partial)
+            }
+
+            #[inline]
+            fn create_front(
+                self,
+                pad: Option<Vector<A, B, S>>,
+            ) -> (Self::Vectorizer, usize, Option<Vector<A, B, S>>) {
+                let (_, len, partial) =
+                    Vectorizable::<Vector<A, B, S>>::create_front(&self[..], pad);
+                let me = OwnedVectorizer {
+                    offset: self.len() % S,
+                    data: self,
+                    _vector: PhantomData,
                 };
-                (vectorizer, (created.0).1, pad)
+                (me, len, partial)
+            }
+
+            #[inline]
+            fn remainder(&self) -> usize {
+                self.len() % S
+            }
+
+            #[inline]
+            fn empty_partial(pad: Vector<A, B, S>) -> Option<Vector<A, B, S>> {
+                Some(pad)
+            }
+        }
+    };
+}
+
+vectorizable_owned!(Vec<B>);
+vectorizable_owned!(Box<[B]>);
+
+macro_rules! vectorizable_tuple {
+    (@create $me: ident, $pad: ident, $method: ident, $(($X: ident, $X0: tt)),*) => {{
+        let pad = match $pad {
+            Some(pad) => ($(Some(pad.$X0)),*),
+            None => Default::default(), // Bunch of Nones in a tuple.. (None, None, None)...
+        };
+        let created = ($($me.$X0.$method(pad.$X0)),*);
+        $(
+            // TODO: We may want to support this in the padded mode eventually by
+            // creating more paddings
+            assert_eq!(
+                (created.0).1,
+                created.$X0.1,
+                "Vectorizing data of different lengths"
+            );
+        )*
+        let vectorizer = ($(created.$X0.0),*);
+        let need_pad = false $(|| created.$X0.2.is_some())*;
+        let pad = if need_pad {
+            // If any of them needs padding, we are in the padded mode and have the pads
+            Some(($(
+                match created.$X0.2 {
+                    Some(partial) => partial,
+                    None => $X::empty_partial(pad.$X0.unwrap())
+                        .expect("Paddings are not the same for all vectorized data"),
+                }
+            ),*))
+        } else {
+            None
+        };
+        (vectorizer, (created.0).1, pad)
+    }};
+    ($(($X: ident, $XR: ident, $X0: tt)),*) => {
+        impl<$($X, $XR),*> Vectorizer<($($XR),*)> for ($($X),*)
+        where
+            $($X: Vectorizer<$XR>,)*
+        {
+            #[inline(always)]
+            unsafe fn get(&mut self, idx: usize) -> ($($XR),*) {
+                ($(self.$X0.get(idx)),*)
+            }
+        }
+
+        impl<$($X, $XR),*> Vectorizable<($($XR),*)> for ($($X),*)
+        where
+            $($X: Vectorizable<$XR>, $X::Padding: Copy,)*
+        {
+            type Vectorizer = ($($X::Vectorizer),*);
+            type Padding = ($($X::Padding),*);
+            #[inline]
+            #[allow(clippy::eq_op)]
+            fn create(self, pad: Option<Self::Padding>)
+                -> (Self::Vectorizer, usize, Option<($($XR),*)>)
+            {
+                vectorizable_tuple!(@create self, pad, create, $(($X, $X0)),*)
+            }
+
+            #[inline]
+            #[allow(clippy::eq_op)]
+            fn create_front(self, pad: Option<Self::Padding>)
+                -> (Self::Vectorizer, usize, Option<($($XR),*)>)
+            {
+                vectorizable_tuple!(@create self, pad, create_front, $(($X, $X0)),*)
+            }
+
+            #[inline]
+            fn remainder(&self) -> usize {
+                0 $(.max(self.$X0.remainder()))*
+            }
+
+            #[inline]
+            fn empty_partial(pad: Self::Padding) -> Option<($($XR),*)> {
+                Some(($($X::empty_partial(pad.$X0)?),*))
             }
         }
     }
@@ -607,6 +1582,67 @@ where
     }
 }
 
+/// Shared implementation of `create` and `create_front` for arrays.
+#[inline(always)]
+fn create_array<T, TR, const S: usize>(
+    arr: [T; S],
+    pad: Option<[T::Padding; S]>,
+    front: bool,
+) -> ([T::Vectorizer; S], usize, Option<[TR; S]>)
+where
+    T: Vectorizable<TR> + Copy,
+    T::Padding: Copy,
+{
+    let mut vectorizer = MaybeUninit::<[T::Vectorizer; S]>::uninit();
+    let mut size = 0;
+    let mut padding = MaybeUninit::<[TR; S]>::uninit();
+    let mut seen_some_pad = false;
+    let mut seen_none_pad = false;
+    let mut needs_empty = [false; S];
+    unsafe {
+        for i in 0..S {
+            let (v, s, p) = if front {
+                arr[i].create_front(pad.map(|p| p[i]))
+            } else {
+                arr[i].create(pad.map(|p| p[i]))
+            };
+            ptr::write(vectorizer.as_mut_ptr().cast::<T::Vectorizer>().add(i), v);
+            if i == 0 {
+                size = s;
+            } else {
+                assert_eq!(size, s, "Vectorized lengths inconsistent across the array",);
+            }
+            match p {
+                Some(p) => {
+                    seen_some_pad = true;
+                    ptr::write(padding.as_mut_ptr().cast::<TR>().add(i), p);
+                }
+                None => {
+                    seen_none_pad = true;
+                    needs_empty[i] = true;
+                }
+            }
+        }
+        if seen_some_pad && seen_none_pad {
+            // Padded mode (as some has a padding) and some don't need it, create empty ones
+            let pad = pad.unwrap();
+            for (i, needs) in needs_empty.iter().enumerate() {
+                if *needs {
+                    let empty =
+                        T::empty_partial(pad[i]).expect("Paddings inconsistent across the array");
+                    ptr::write(padding.as_mut_ptr().cast::<TR>().add(i), empty);
+                }
+            }
+        }
+        let padding = if seen_some_pad {
+            Some(padding.assume_init())
+        } else {
+            None
+        };
+        (vectorizer.assume_init(), size, padding)
+    }
+}
+
 impl<T, TR, const S: usize> Vectorizable<[TR; S]> for [T; S]
 where
     T: Vectorizable<TR> + Copy,
@@ -616,43 +1652,40 @@ where
     type Padding = [T::Padding; S];
     #[inline]
     fn create(self, pad: Option<Self::Padding>) -> (Self::Vectorizer, usize, Option<[TR; S]>) {
-        let mut vectorizer = MaybeUninit::<Self::Vectorizer>::uninit();
-        let mut size = 0;
-        let mut padding = MaybeUninit::<[TR; S]>::uninit();
-        let mut seen_some_pad = false;
-        let mut seen_none_pad = false;
-        unsafe {
-            for i in 0..S {
-                let (v, s, p) = self[i].create(pad.map(|p| p[i]));
-                ptr::write(vectorizer.as_mut_ptr().cast::<T::Vectorizer>().add(i), v);
-                if i == 0 {
-                    size = s;
-                } else {
-                    assert_eq!(size, s, "Vectorized lengths inconsistent across the array",);
-                }
-                match p {
-                    Some(p) => {
-                        seen_some_pad = true;
-                        ptr::write(padding.as_mut_ptr().cast::<TR>().add(i), p);
-                    }
-                    None => seen_none_pad = true,
-                }
+        create_array(self, pad, false)
+    }
+
+    #[inline]
+    fn create_front(
+        self,
+        pad: Option<Self::Padding>,
+    ) -> (Self::Vectorizer, usize, Option<[TR; S]>) {
+        create_array(self, pad, true)
+    }
+
+    #[inline]
+    fn remainder(&self) -> usize {
+        self.iter().map(|t| t.remainder()).max().unwrap_or(0)
+    }
+
+    #[inline]
+    fn empty_partial(pad: Self::Padding) -> Option<[TR; S]> {
+        let mut result = MaybeUninit::<[TR; S]>::uninit();
+        for (i, pad) in pad.iter().enumerate() {
+            match T::empty_partial(*pad) {
+                Some(partial) => unsafe {
+                    ptr::write(result.as_mut_ptr().cast::<TR>().add(i), partial);
+                },
+                // Note: we may leak the already created ones, but these are just vectors or
+                // proxies without any data to store.
+                None => return None,
             }
-            assert!(
-                !seen_some_pad || !seen_none_pad,
-                "Paddings inconsistent across the array",
-            );
-            let padding = if seen_some_pad {
-                Some(padding.assume_init())
-            } else {
-                None
-            };
-            (vectorizer.assume_init(), size, padding)
         }
+        Some(unsafe { result.assume_init() })
     }
 }
 
-impl<'a, T> Vectorizer<T> for &'a [T]
+impl<T> Vectorizer<T> for &[T]
 where
     T: Copy,
 {
@@ -694,8 +1727,59 @@ impl<'a, A: Align, B: Repr, const S: usize> Vectorizable<&'a mut Vector<A, B, S>
     }
 }
 
+/// Collecting an iterator of vectors back into scalars.
+///
+/// This closes the loop of vectorizing a slice (with padding), transforming the vectors and
+/// materializing the result. The padding lanes of the last vector are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// let data = [1, 2, 3, 4, 5];
+/// let doubled = data
+///     .vectorize_pad(u32x4::default())
+///     .map(|v: u32x4| v * 2)
+///     .collect_scalars(data.len());
+/// assert_eq!(doubled, [2, 4, 6, 8, 10]);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait CollectScalars<B>: Iterator {
+    /// Collects the lanes of all the vectors into a `Vec` of the given length.
+    ///
+    /// # Panics
+    ///
+    /// If the vectors don't have enough lanes to fill the length.
+    fn collect_scalars(self, len: usize) -> Vec<B>;
+}
+
+#[cfg(feature = "alloc")]
+impl<I, A, B, const S: usize> CollectScalars<B> for I
+where
+    I: Iterator<Item = Vector<A, B, S>>,
+    A: Align,
+    B: Repr,
+{
+    fn collect_scalars(self, len: usize) -> Vec<B> {
+        let mut result = Vec::with_capacity(len + S);
+        for v in self {
+            result.extend_from_slice(&v[..]);
+        }
+        assert!(
+            result.len() >= len,
+            "Not enough lanes to collect ({} vs {})",
+            result.len(),
+            len,
+        );
+        result.truncate(len);
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use core::fmt::Debug;
+
     use super::*;
     use crate::prelude::*;
 
@@ -723,6 +1807,576 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_owned() {
+        let data = (0..=10u32).collect::<Vec<_>>();
+        let total = data
+            .vectorize_pad(u32x4::default())
+            .sum::<u32x4>()
+            .horizontal_sum();
+        assert_eq!(total, 55);
+
+        let boxed = (0..8u32).collect::<Box<[_]>>();
+        let v = boxed.vectorize().collect::<Vec<u32x4>>();
+        assert_eq!(v, [u32x4::new([0, 1, 2, 3]), u32x4::new([4, 5, 6, 7])]);
+    }
+
+    #[test]
+    fn iter_array_ref() {
+        let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let mut cnt = 0;
+        for v in (&data).vectorize() {
+            let v: f32x4 = v;
+            assert_eq!(v[0], data[cnt * 4]);
+            cnt += 1;
+        }
+        assert_eq!(cnt, 2);
+
+        let mut out = [0.0f32; 8];
+        for (mut o, i) in (&mut out, &data).vectorize() {
+            let i: f32x4 = i;
+            *o = i * 2.0;
+        }
+        assert_eq!(out, [2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0]);
+    }
+
+    /// Drives the iterator by a pseudo-random mix of next, next_back and nth, checking both the
+    /// results and the len against the collected items.
+    fn check_len<I, F>(make: F, seed: u32)
+    where
+        I: DoubleEndedIterator + ExactSizeIterator,
+        I::Item: Debug + PartialEq,
+        F: Fn() -> I,
+    {
+        let model = make().collect::<Vec<_>>();
+        let mut it = make();
+        let (mut lo, mut hi) = (0, model.len());
+        let mut rng = seed;
+        while lo < hi {
+            assert_eq!(it.len(), hi - lo);
+            assert_eq!(it.size_hint(), (hi - lo, Some(hi - lo)));
+            rng = rng.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            match (rng >> 16) % 3 {
+                0 => {
+                    assert_eq!(it.next().as_ref(), model.get(lo));
+                    lo += 1;
+                }
+                1 => {
+                    assert_eq!(it.next_back().as_ref(), model.get(hi - 1));
+                    hi -= 1;
+                }
+                _ => {
+                    let n = ((rng >> 20) % 3) as usize;
+                    let expected = if lo + n < hi { model.get(lo + n) } else { None };
+                    assert_eq!(it.nth(n).as_ref(), expected);
+                    lo = (lo + n + 1).min(hi);
+                }
+            }
+        }
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+        assert_eq!(it.len(), 0);
+    }
+
+    #[test]
+    fn len_exact() {
+        let data = (0..30u32).collect::<Vec<_>>();
+        for len in [0, 3, 4, 16, 17, 30] {
+            let data = &data[..len];
+            for seed in 0..20 {
+                check_len(|| data.vectorize_pad(u32x4::default()), seed);
+                check_len(|| data.vectorize_rev(u32x4::default()), seed);
+                check_len(|| Vectorizable::<u32x4>::vectorize_masked(data), seed);
+                check_len(
+                    || Vectorizable::<u32x4>::vectorize_enumerate::<u32x4>(data, u32x4::default()),
+                    seed,
+                );
+                check_len(|| data.vectorize_pad(u32x4::default()).skip(1), seed);
+                check_len(
+                    || Strided::new(data, 3).vectorize_pad(u32x2::default()),
+                    seed,
+                );
+                if len % 2 == 0 {
+                    check_len(|| Vectorizable::<u32x2>::vectorize(data), seed);
+                }
+            }
+        }
+
+        // The adapters keep the len exact as well
+        let it = data[..].vectorize_pad(u32x4::default());
+        assert_eq!(it.len(), 8);
+        let mut it = it.step_by(3);
+        assert_eq!(it.len(), 3);
+        it.next();
+        assert_eq!(it.len(), 2);
+        let mut it = data[..].vectorize_pad(u32x4::default()).skip(2).rev();
+        assert_eq!(it.len(), 6);
+        it.nth(2);
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.count(), 3);
+    }
+
+    #[test]
+    #[allow(clippy::iter_nth_zero)] // We want to test the nth itself
+    fn nth() {
+        let data = (0..18u32).collect::<Vec<_>>();
+        let iter = || data[..].vectorize_pad(u32x4::default());
+        let all: Vec<u32x4> = iter().collect();
+        assert_eq!(all.len(), 5);
+
+        for n in 0..7 {
+            let mut it = iter();
+            assert_eq!(it.nth(n), all.get(n).copied(), "nth({})", n);
+            // What is left after that
+            let rest = it.collect::<Vec<_>>();
+            assert_eq!(
+                &rest[..],
+                all.get(n + 1..).unwrap_or(&[]),
+                "rest after nth({})",
+                n
+            );
+        }
+
+        // Repeated nth calls
+        let mut it = iter();
+        assert_eq!(it.nth(1), Some(all[1]));
+        assert_eq!(it.nth(1), Some(all[3]));
+        // Exactly the partial
+        assert_eq!(it.nth(0), Some(all[4]));
+        assert_eq!(it.nth(0), None);
+
+        // Past the end doesn't return the partial either
+        let mut it = iter();
+        assert_eq!(it.nth(5), None);
+        assert_eq!(it.next(), None);
+
+        // Without any partial
+        let mut it = data[..16].vectorize();
+        assert_eq!(it.nth(3), Some(all[3]));
+        assert_eq!(it.nth(0), None);
+    }
+
+    #[test]
+    fn rev() {
+        let data = (0..18u32).collect::<Vec<_>>();
+        let forward = data[..].vectorize_pad(u32x4::default()).collect::<Vec<_>>();
+        let mut backward = data[..]
+            .vectorize_pad(u32x4::default())
+            .rev()
+            .collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        // Mixing both ends
+        let mut it = data[..].vectorize_pad(u32x4::default());
+        assert_eq!(it.next_back(), Some(forward[4]));
+        assert_eq!(it.next(), Some(forward[0]));
+        assert_eq!(it.next_back(), Some(forward[3]));
+        assert_eq!(it.next_back(), Some(forward[2]));
+        assert_eq!(it.next(), Some(forward[1]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn aligned() {
+        let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+        let data = unsafe { slice::from_raw_parts(vectors.as_ptr().cast::<u32>(), 8) };
+        let v = Aligned::<u32x4>::new(data)
+            .vectorize()
+            .collect::<Vec<u32x4>>();
+        assert_eq!(v, vectors);
+
+        let v = Aligned::<u32x4>::new(&data[..6])
+            .vectorize_pad(u32x4::default())
+            .collect::<Vec<_>>();
+        assert_eq!(v, [vectors[0], u32x4::new([5, 6, 0, 0])]);
+    }
+
+    #[test]
+    fn aligned_rev() {
+        let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+        let data = unsafe { slice::from_raw_parts(vectors.as_ptr().cast::<u32>(), 8) };
+        let v = (Aligned::<u32x4>::new(data), data)
+            .vectorize_rev((u32x4::default(), u32x4::default()))
+            .collect::<Vec<_>>();
+        assert_eq!(v, [(vectors[0], vectors[0]), (vectors[1], vectors[1])]);
+
+        // Only a partial, nothing to misalign
+        let v = Aligned::<u32x4>::new(&data[..3])
+            .vectorize_rev(u32x4::default())
+            .collect::<Vec<_>>();
+        assert_eq!(v, [u32x4::new([1, 2, 3, 0])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Aligned data can't have the partial vector at the front")]
+    fn aligned_rev_uneven() {
+        let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+        let data = unsafe { slice::from_raw_parts(vectors.as_ptr().cast::<u32>(), 6) };
+        Aligned::<u32x4>::new(data).vectorize_rev(u32x4::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice not aligned for the vector")]
+    fn aligned_misaligned() {
+        let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+        let data = unsafe { slice::from_raw_parts(vectors.as_ptr().cast::<u32>(), 8) };
+        Aligned::<u32x4>::new(&data[1..]);
+    }
+
+    #[test]
+    fn aligned_empty() {
+        let data: &[u32] = &[];
+        assert_eq!(Aligned::<u32x4>::new(data).vectorize().count(), 0);
+        let v = Aligned::<u32x4>::new(data)
+            .vectorize_pad(u32x4::default())
+            .collect::<Vec<_>>();
+        assert!(v.is_empty());
+        // An empty subslice at a misaligned position is fine too
+        let vectors = [u32x4::new([1, 2, 3, 4])];
+        let data = unsafe { slice::from_raw_parts(vectors.as_ptr().cast::<u32>(), 4) };
+        assert_eq!(Aligned::<u32x4>::new(&data[1..1]).vectorize().count(), 0);
+    }
+
+    #[test]
+    fn strided() {
+        let data = (0..10u32).collect::<Vec<_>>();
+        let v = Strided::new(&data[..8], 2)
+            .vectorize()
+            .collect::<Vec<u32x4>>();
+        assert_eq!(v, vec![u32x4::new([0, 2, 4, 6])]);
+
+        // The last element doesn't need the whole stride after it
+        let strided = Strided::new(&data[..], 3);
+        assert_eq!(strided.len(), 4);
+        let v = strided.vectorize().collect::<Vec<u32x2>>();
+        assert_eq!(v, vec![u32x2::new([0, 3]), u32x2::new([6, 9])]);
+
+        let v = Strided::new(&data[1..], 2)
+            .vectorize_pad(u32x4::splat(100))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![u32x4::new([1, 3, 5, 7]), u32x4::new([9, 100, 100, 100])]
+        );
+
+        let v = Strided::new(&data[..], 2)
+            .vectorize_rev(u32x4::splat(100))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![u32x4::new([0, 100, 100, 100]), u32x4::new([2, 4, 6, 8])]
+        );
+
+        // Everything in the head, the slice ends before the last full stride
+        let v = Strided::new(&[1, 10, 2, 20, 3], 2)
+            .vectorize_rev(u32x4::splat(0))
+            .collect::<Vec<_>>();
+        assert_eq!(v, vec![u32x4::new([1, 2, 3, 0])]);
+        let v = Strided::new(&data[..9], 2)
+            .vectorize_rev(u32x4::splat(100))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![u32x4::new([0, 100, 100, 100]), u32x4::new([2, 4, 6, 8])]
+        );
+
+        let v = crate::vectorize_strided(&data[..8], 2).collect::<Vec<u32x4>>();
+        assert_eq!(v, vec![u32x4::new([0, 2, 4, 6])]);
+
+        let v = Strided::new(&data[..], 1)
+            .vectorize_pad(u32x4::default())
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(v[1], u32x4::new([4, 5, 6, 7]));
+
+        let mut out = [0; 5];
+        for (x, mut o) in (Strided::new(&data[..], 2), &mut out[..])
+            .vectorize_pad((u32x2::default(), u32x2::default()))
+        {
+            *o = x * u32x2::splat(10);
+        }
+        assert_eq!(out, [0, 20, 40, 60, 80]);
+
+        assert_eq!(
+            Strided::new(&data[..0], 2)
+                .vectorize_pad(u32x4::default())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Stride must not be 0")]
+    fn strided_zero() {
+        Strided::new(&[1, 2, 3], 0);
+    }
+
+    #[test]
+    fn vectorize_masked() {
+        let data = (1..=13u32).collect::<Vec<_>>();
+        let all = m32x4::splat(m32::TRUE);
+        let v = data[..].vectorize_masked().collect::<Vec<(u32x4, _)>>();
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[0], (u32x4::new([1, 2, 3, 4]), all));
+        assert_eq!(v[2], (u32x4::new([9, 10, 11, 12]), all));
+        let (last, mask) = v[3];
+        assert_eq!(last, u32x4::new([13, 0, 0, 0]));
+        assert_eq!(mask.bitmask(), 0b0001);
+
+        let mut it = data[..].vectorize_masked();
+        let (last, mask): (u32x4, _) = it.next_back().unwrap();
+        assert_eq!(last[0], 13);
+        assert_eq!(mask.bitmask(), 0b0001);
+        assert_eq!(it.next_back().unwrap().1, all);
+
+        // Divisible length has only full masks
+        assert!(data[..12]
+            .vectorize_masked()
+            .all(|(_, m): (u32x4, _)| m == all));
+    }
+
+    #[test]
+    fn create_info() {
+        let data = (0..20u32).collect::<Vec<_>>();
+        let pad = u32x4::default();
+        for len in 0..data.len() {
+            let (_, info, partial) = Vectorizable::<u32x4>::create_info(&data[..len], Some(pad));
+            assert_eq!(info.full_vectors, len / 4);
+            assert_eq!(info.remainder, len % 4);
+            assert_eq!(partial.is_some(), len % 4 != 0);
+        }
+
+        let (_, info, _) =
+            Vectorizable::<(u32x4, u32x4)>::create_info((&data[..7], &data[..5]), Some((pad, pad)));
+        assert_eq!(
+            info,
+            VectorizeInfo {
+                full_vectors: 1,
+                remainder: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let empty: &[u32] = &[];
+        assert_eq!(crate::vectorize::<u32x4, _>(empty).count(), 0);
+        assert_eq!(empty.vectorize_pad(u32x4::splat(1)).count(), 0);
+        assert_eq!(empty.vectorize_pad(u32x4::splat(1)).len(), 0);
+        assert!(empty.vectorize_pad(u32x4::splat(1)).next_back().is_none());
+        assert!(empty.vectorize_rev(u32x4::splat(1)).next().is_none());
+
+        let (_, info, partial) = Vectorizable::<u32x4>::create_info(empty, Some(u32x4::default()));
+        assert_eq!(
+            info,
+            VectorizeInfo {
+                full_vectors: 0,
+                remainder: 0,
+            }
+        );
+        assert!(partial.is_none());
+
+        let empty_mut: &mut [u32] = &mut [];
+        assert_eq!(empty_mut.vectorize_pad(u32x4::splat(1)).count(), 0);
+        let empty_mut: &mut [u32] = &mut [];
+        let pads = (u32x4::default(), u32x4::default());
+        assert_eq!((empty_mut, empty).vectorize_pad(pads).count(), 0);
+    }
+
+    #[test]
+    fn partial_write_back() {
+        let mut data = [0u32; 12];
+        let pad = u32x4::splat(7);
+        for mut v in (&mut data[..6]).vectorize_pad(pad) {
+            // Overwrite all the lanes, including the padding ones in the partial vector
+            *v = u32x4::splat(1);
+        }
+        // Only the 6 elements are written, the padding lanes don't leak past the end of the slice
+        assert_eq!(data, [1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(pad, u32x4::splat(7));
+
+        let mut data = [0u32; 12];
+        for mut v in (&mut data[6..]).vectorize_rev(pad) {
+            *v = u32x4::splat(2);
+        }
+        assert_eq!(data, [0, 0, 0, 0, 0, 0, 2, 2, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_scalars() {
+        let data = (0..33u32).collect::<Vec<_>>();
+        let result = data[..]
+            .vectorize_pad(u32x4::splat(1000))
+            .map(|v: u32x4| v + 1)
+            .collect_scalars(data.len());
+        assert_eq!(result.len(), 33);
+        assert_eq!(result, (1..34).collect::<Vec<_>>());
+
+        let empty = (&[] as &[u32])
+            .vectorize_pad(u32x4::default())
+            .map(|v: u32x4| v)
+            .collect_scalars(0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "Not enough lanes to collect (8 vs 9)")]
+    fn collect_scalars_short() {
+        [u32x4::default(); 2].iter().copied().collect_scalars(9);
+    }
+
+    #[test]
+    fn update_where() {
+        let mut data = [1, 8, -3, 12, 5, 20, 7];
+        let one = i32x4::splat(1);
+        let limit = i32x4::splat(6);
+        for mut v in (&mut data).vectorize_pad(i32x4::default()) {
+            let small = v.lt(limit);
+            let incremented = *v + one;
+            v.update_where(small, incremented);
+        }
+        assert_eq!(data, [2, 8, -2, 12, 6, 20, 7]);
+    }
+
+    #[test]
+    fn vectorize_enumerate() {
+        let data = (0..10u32).map(|i| i * 10).collect::<Vec<_>>();
+        let all = data[..]
+            .vectorize_enumerate(u32x4::default())
+            .collect::<Vec<(u32x4, u32x4)>>();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].0, u32x4::new([0, 1, 2, 3]));
+        assert_eq!(all[1].0, u32x4::new([4, 5, 6, 7]));
+        assert_eq!(all[2].0, u32x4::new([8, 9, 10, 11]));
+        for (idx, v) in &all {
+            for lane in 0..4 {
+                if idx[lane] < 10 {
+                    assert_eq!(v[lane], idx[lane] * 10);
+                }
+            }
+        }
+
+        let mut back = data[..]
+            .vectorize_enumerate(u32x4::default())
+            .rev()
+            .collect::<Vec<(u32x4, u32x4)>>();
+        back.reverse();
+        assert_eq!(all, back);
+
+        // Mixing both ends, composites and different type of the indices
+        let pad = (u32x4::default(), u32x4::default());
+        let mut it = (&data[..], &data[..]).vectorize_enumerate::<i64x4>(pad);
+        let (idx, (a, b)) = it.next_back().unwrap();
+        assert_eq!(idx, i64x4::iota(8));
+        assert_eq!(a, u32x4::new([80, 90, 0, 0]));
+        assert_eq!(a, b);
+        assert_eq!(it.next().unwrap().0, i64x4::iota(0));
+        assert_eq!(it.next_back().unwrap().0, i64x4::iota(4));
+        assert!(it.next().is_none());
+        assert!(it.next_back().is_none());
+    }
+
+    #[test]
+    fn vectorize_rev() {
+        let mut data = (0..33u32).collect::<Vec<_>>();
+        let ones = u32x4::splat(1);
+        let mut it = (&mut data[..]).vectorize_rev(u32x4::splat(100));
+        assert_eq!(it.len(), 9);
+        let mut head = it.next().unwrap();
+        assert_eq!(*head, u32x4::new([0, 100, 100, 100]));
+        *head = ones + *head;
+        // Only the first lane is written back
+        drop(head);
+        for (i, mut v) in it.enumerate() {
+            let start = 1 + 4 * i as u32;
+            assert_eq!(*v, u32x4::new([start, start + 1, start + 2, start + 3]));
+            *v = ones + *v;
+        }
+        assert_eq!(data, (1..34).collect::<Vec<_>>());
+
+        // The partial is the last one from the back
+        let mut it = data[..].vectorize_rev(u32x4::default());
+        assert_eq!(it.next_back(), Some(u32x4::new([30, 31, 32, 33])));
+        assert_eq!(it.nth(1), Some(u32x4::new([2, 3, 4, 5])));
+        assert_eq!(it.by_ref().rev().last(), Some(u32x4::new([6, 7, 8, 9])));
+        assert_eq!(it.next(), None);
+
+        // Composites align all the parts to the end
+        let other = (0..32u32).collect::<Vec<_>>();
+        let mut it = (&data[..], &other[..]).vectorize_rev((u32x4::default(), u32x4::default()));
+        assert_eq!(
+            it.next(),
+            Some((u32x4::new([1, 0, 0, 0]), u32x4::default()))
+        );
+        assert_eq!(
+            it.next(),
+            Some((u32x4::new([2, 3, 4, 5]), u32x4::new([0, 1, 2, 3])))
+        );
+    }
+
+    #[test]
+    fn iter_triple() {
+        let a = (0..12u32).collect::<Vec<_>>();
+        let b = (100..112u32).collect::<Vec<_>>();
+        let mut c = [0u32; 12];
+        for (a, b, mut c) in (&a[..], &b[..], &mut c[..]).vectorize() {
+            let (a, b): (u32x4, u32x4) = (a, b);
+            *c = a * b;
+        }
+        for i in 0..12 {
+            assert_eq!(c[i], a[i] * b[i]);
+        }
+    }
+
+    #[test]
+    fn iter_uneven_pad() {
+        let long = (0..10u16).collect::<Vec<_>>();
+        let short = (0..8u16).collect::<Vec<_>>();
+        let mut out = [0u16; 8];
+        let pad = (u16x4::splat(100), u16x4::splat(200), u16x4::default());
+        let mut cnt = 0;
+        for (l, s, mut o) in (&long[..], &short[..], &mut out[..]).vectorize_pad(pad) {
+            *o = l + s;
+            cnt += 1;
+        }
+        assert_eq!(cnt, 3);
+        assert_eq!(out, [0, 2, 4, 6, 8, 10, 12, 14]);
+
+        let v = (&long[..], &short[..])
+            .vectorize_pad((u16x4::splat(100), u16x4::splat(200)))
+            .last()
+            .unwrap();
+        assert_eq!(v, (u16x4::new([8, 9, 100, 100]), u16x4::splat(200)));
+
+        // Same for arrays
+        let mut cnt = 0;
+        for [l, s] in [&long[..], &short[..]].vectorize_pad([u16x4::splat(1); 2]) {
+            let l: u16x4 = l;
+            if cnt == 2 {
+                assert_eq!(l, u16x4::new([8, 9, 1, 1]));
+                assert_eq!(s, u16x4::splat(1));
+            }
+            cnt += 1;
+        }
+        assert_eq!(cnt, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectorizing data of different lengths")]
+    fn iter_uneven_too_much() {
+        let long = [0u16; 12];
+        let short = [0u16; 8];
+        (&long[..], &short[..])
+            .vectorize_pad((u16x4::default(), u16x4::default()))
+            .for_each(|(_, _): (u16x4, u16x4)| ());
+    }
+
     // Here, one of the inputs is already vectorized
     #[test]
     fn iter_prevec() {
@@ -0,0 +1,286 @@
+//! Support for parallel iteration through [`rayon`].
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use super::{
+    AlignedVectorizer, MutProxy, ReadVectorizer, StridedVectorizer, VectorizedIter, Vectorizer,
+    WriteVectorizer,
+};
+use crate::inner::Repr;
+use crate::vector::align::Align;
+use crate::Vector;
+
+/// A [`Vectorizer`] that can be split into parts for different threads.
+///
+/// This is an implementation detail of [`ParVectorizedIter`]. Splitting creates another vectorizer
+/// over the same data, so the parts must be used with disjoint ranges of indices. This is what
+/// the parallel iterator does, but copying a vectorizer in general would hand out the same
+/// (possibly mutable) vectors multiple times.
+///
+/// # Safety
+///
+/// The vectorizer created by [`split_off`][ParVectorizer::split_off] must be usable from another
+/// thread at the same time as the original, as long as each index is still accessed at most once
+/// through all of them together.
+#[doc(hidden)]
+pub unsafe trait ParVectorizer<R>: Vectorizer<R> + Send + Sized {
+    /// Creates another vectorizer over the same data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure each index is accessed at most once across the original and all
+    /// the split off vectorizers.
+    unsafe fn split_off(&self) -> Self;
+}
+
+unsafe impl<'a, A: Align, B: Repr, const S: usize> ParVectorizer<Vector<A, B, S>>
+    for ReadVectorizer<'a, A, B, S>
+{
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        *self
+    }
+}
+
+unsafe impl<'a, A: Align, B: Repr, const S: usize> ParVectorizer<MutProxy<'a, B, Vector<A, B, S>>>
+    for WriteVectorizer<'a, A, B, S>
+{
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        WriteVectorizer {
+            start: self.start,
+            _vector: PhantomData,
+            _slice: PhantomData,
+        }
+    }
+}
+
+unsafe impl<A: Align, B: Repr, const S: usize> ParVectorizer<Vector<A, B, S>>
+    for AlignedVectorizer<'_, Vector<A, B, S>>
+where
+    Vector<A, B, S>: Sync,
+{
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        *self
+    }
+}
+
+unsafe impl<'a, A: Align, B: Repr, const S: usize> ParVectorizer<Vector<A, B, S>>
+    for StridedVectorizer<'a, A, B, S>
+{
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        *self
+    }
+}
+
+unsafe impl<T: Copy + Sync> ParVectorizer<T> for &[T] {
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        self
+    }
+}
+
+macro_rules! par_vectorizer_tuple {
+    ($(($X: ident, $XR: ident, $X0: tt)),*) => {
+        unsafe impl<$($X, $XR),*> ParVectorizer<($($XR),*)> for ($($X),*)
+        where
+            $($X: ParVectorizer<$XR>,)*
+        {
+            #[inline(always)]
+            unsafe fn split_off(&self) -> Self {
+                ($(self.$X0.split_off()),*)
+            }
+        }
+    };
+}
+
+par_vectorizer_tuple!((A, AR, 0), (B, BR, 1));
+par_vectorizer_tuple!((A, AR, 0), (B, BR, 1), (C, CR, 2));
+par_vectorizer_tuple!((A, AR, 0), (B, BR, 1), (C, CR, 2), (D, DR, 3));
+par_vectorizer_tuple!((A, AR, 0), (B, BR, 1), (C, CR, 2), (D, DR, 3), (E, ER, 4));
+par_vectorizer_tuple!(
+    (A, AR, 0),
+    (B, BR, 1),
+    (C, CR, 2),
+    (D, DR, 3),
+    (E, ER, 4),
+    (F, FR, 5)
+);
+par_vectorizer_tuple!(
+    (A, AR, 0),
+    (B, BR, 1),
+    (C, CR, 2),
+    (D, DR, 3),
+    (E, ER, 4),
+    (F, FR, 5),
+    (G, GR, 6)
+);
+par_vectorizer_tuple!(
+    (A, AR, 0),
+    (B, BR, 1),
+    (C, CR, 2),
+    (D, DR, 3),
+    (E, ER, 4),
+    (F, FR, 5),
+    (G, GR, 6),
+    (H, HR, 7)
+);
+
+unsafe impl<T, TR, const S: usize> ParVectorizer<[TR; S]> for [T; S]
+where
+    T: ParVectorizer<TR>,
+{
+    #[inline(always)]
+    unsafe fn split_off(&self) -> Self {
+        let mut res = MaybeUninit::<[T; S]>::uninit();
+        for (i, v) in self.iter().enumerate() {
+            ptr::write(res.as_mut_ptr().cast::<T>().add(i), v.split_off());
+        }
+        res.assume_init()
+    }
+}
+
+/// The parallel iterator returned by [`Vectorizable::into_par_vectorize`].
+///
+/// This is the parallel counterpart of [`VectorizedIter`]. The work is split into parts
+/// consisting of disjoint ranges of vectors, each of them handled by a [`VectorizedIter`].
+///
+/// [`Vectorizable::into_par_vectorize`]: super::Vectorizable::into_par_vectorize
+#[derive(Debug)]
+pub struct ParVectorizedIter<V, R> {
+    inner: VectorizedIter<V, (), R>,
+}
+
+impl<V, R> ParVectorizedIter<V, R> {
+    pub(super) fn new(vectorizer: V, len: usize) -> Self {
+        Self {
+            inner: VectorizedIter {
+                partial: (),
+                vectorizer,
+                left: 0,
+                right: len,
+                _result: PhantomData,
+            },
+        }
+    }
+}
+
+impl<V, R> ParallelIterator for ParVectorizedIter<V, R>
+where
+    V: ParVectorizer<R>,
+    R: Send,
+{
+    type Item = R;
+
+    #[inline]
+    fn drive_unindexed<C: UnindexedConsumer<R>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<V, R> IndexedParallelIterator for ParVectorizedIter<V, R>
+where
+    V: ParVectorizer<R>,
+    R: Send,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn drive<C: Consumer<R>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn with_producer<CB: ProducerCallback<R>>(self, callback: CB) -> CB::Output {
+        callback.callback(self.inner)
+    }
+}
+
+impl<V, R> Producer for VectorizedIter<V, (), R>
+where
+    V: ParVectorizer<R>,
+    R: Send,
+{
+    type Item = R;
+    type IntoIter = Self;
+
+    #[inline]
+    fn into_iter(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.left + index;
+        assert!(mid <= self.right);
+        // The split off vectorizer goes to an iterator with a disjoint range, so each index is still
+        // used at most once.
+        let right = VectorizedIter {
+            partial: (),
+            vectorizer: unsafe { self.vectorizer.split_off() },
+            left: mid,
+            right: self.right,
+            _result: PhantomData,
+        };
+        let left = VectorizedIter { right: mid, ..self };
+        (left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn par_sum() {
+        // Small integers, so the sums are exact regardless of the order of additions
+        let data = (0..1 << 20).map(|i| (i % 16) as f32).collect::<Vec<_>>();
+        let seq = data[..].vectorize().sum::<f32x8>().horizontal_sum();
+        let par = data[..]
+            .into_par_vectorize()
+            .sum::<f32x8>()
+            .horizontal_sum();
+        assert_eq!(seq, par);
+        assert_eq!(seq, 7.5 * (1 << 20) as f32);
+    }
+
+    #[test]
+    fn par_mut() {
+        let data = (0..1 << 16).collect::<Vec<u32>>();
+        let mut dst = vec![0; data.len()];
+        let two = u32x4::splat(2);
+        (&mut dst[..], &data[..])
+            .into_par_vectorize()
+            .for_each(|(mut d, s)| *d = two * s);
+        assert!(data.iter().zip(&dst).all(|(s, d)| 2 * s == *d));
+    }
+
+    #[test]
+    fn par_array() {
+        let a = (0..1 << 12).collect::<Vec<u32>>();
+        let b = a.iter().map(|i| i * 3).collect::<Vec<_>>();
+        let total = [&a[..], &b[..]]
+            .into_par_vectorize()
+            .map(|[a, b]: [u32x4; 2]| a + b)
+            .sum::<u32x4>()
+            .horizontal_sum();
+        assert_eq!(total, 4 * a.iter().sum::<u32>());
+    }
+}
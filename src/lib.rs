@@ -1,7 +1,7 @@
 #![doc(test(attr(deny(warnings))))]
 #![warn(missing_docs)]
 #![allow(non_camel_case_types)]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 //! This library helps writing code in a way that incentives the compiler to
 //! optimize the results better (without really doing anything itself).
@@ -101,6 +101,29 @@
 //! # dot_product(&[], &[]);
 //! ```
 //!
+//! # Features
+//!
+//! * `std`: Adds methods that need the standard library. These are mostly the floating point
+//!   operations like [`sqrt`][Vector::sqrt], as the core library doesn't provide them. Without
+//!   it (the default), the crate is `no_std`. Implies `alloc`.
+//! * `alloc`: Support for types from the `alloc` crate, like vectorizing owned `Vec`s. This can
+//!   be used in `no_std` environments that have an allocator.
+//! * `rayon`: Parallel vectorized iteration through the [`rayon`](https://docs.rs/rayon) crate
+//!   (see [`into_par_vectorize`][Vectorizable::into_par_vectorize]).
+//! * `serde`: Implements `Serialize` and `Deserialize` for the vectors, as fixed-sized arrays of
+//!   the lanes. Masks are (de)serialized as `bool`s.
+//! * `bytemuck`: Implements the `Zeroable` trait for all vectors and `Pod` for the numeric ones
+//!   from [`types`], for zero-copy casting of byte buffers.
+//! * `num-traits`: Implements the `Zero` and `One` traits for the numeric vectors, to allow
+//!   writing code generic over both scalars and vectors.
+//! * `rand`: Allows generating random vectors (with the `Standard` distribution), each lane
+//!   generated independently.
+//! * `approx`: Implements the `AbsDiffEq`, `RelativeEq` and `UlpsEq` traits, comparing the vectors
+//!   lane-wise. This allows using the `assert_relative_eq!` and similar macros in tests.
+//! * `proptest`: Implements the `Arbitrary` trait, for property testing with the `proptest`
+//!   crate. The lanes are generated independently by the strategy of the base type, custom lane
+//!   strategies can be used through [`Vector::strategy`].
+//!
 //! # Multiversioning and dynamic instruction set selection
 //!
 //! If used as in the examples above, the compiler chooses an instruction set at compile time,
@@ -112,6 +135,51 @@
 //! While this library doesn't provide any direct support for multiversioning, it has been observed
 //! to work reasonably well in combination with the [`multiversion`] crate.
 //!
+//! The library doesn't do the dispatch on the level of individual operations (eg. choosing between
+//! one AVX2 or two SSE instructions for adding two [`u32x8`]s). The vectors are just well aligned
+//! arrays and the actual instructions are chosen by the compiler for the whole function at once.
+//! Checking the CPU on each operation would be more expensive than the operation itself. Instead,
+//! the whole hot function needs to be compiled multiple times, with different instruction sets
+//! enabled, and the right version chosen when calling it. The [`multiversion`] crate automates
+//! that, but it can also be done by hand:
+//!
+//! ```rust
+//! # use slipstream::prelude::*;
+//! // The actual implementation, inlined into each of the versions.
+//! #[inline(always)]
+//! fn sum_impl(data: &[u32]) -> u32 {
+//!     data.vectorize_pad(u32x8::default())
+//!         .sum::<u32x8>()
+//!         .horizontal_sum()
+//! }
+//!
+//! #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! #[target_feature(enable = "avx2")]
+//! unsafe fn sum_avx2(data: &[u32]) -> u32 {
+//!     sum_impl(data)
+//! }
+//!
+//! fn sum_fallback(data: &[u32]) -> u32 {
+//!     sum_impl(data)
+//! }
+//!
+//! fn sum(data: &[u32]) -> u32 {
+//!     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//!     {
+//!         if is_x86_feature_detected!("avx2") {
+//!             // Safe, we've just checked the CPU supports the instructions.
+//!             return unsafe { sum_avx2(data) };
+//!         }
+//!     }
+//!     sum_fallback(data)
+//! }
+//!
+//! let data = (0..100).collect::<Vec<u32>>();
+//! assert_eq!(sum(&data), 4950);
+//! // Both versions compute the same thing
+//! assert_eq!(sum(&data), sum_fallback(&data));
+//! ```
+//!
 //! Note that using a newer and richer instruction set is not always a win. In some cases it can
 //! even lead to performance degradation. In particular:
 //!
@@ -253,6 +321,9 @@
 //! [`simdeez`]: https://crates.io/crates/simdeez
 //! [`safe_simd`]: https://github.com/calebzulawski/safe_simd/
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod iterators;
 pub mod mask;
 pub mod types;
@@ -268,6 +339,8 @@ pub use vector::Vector;
 /// This can be imported to get all the vector types and all the relevant user-facing traits of the
 /// crate.
 pub mod prelude {
+    #[cfg(feature = "alloc")]
+    pub use crate::iterators::CollectScalars as _;
     pub use crate::types::*;
     pub use crate::vector::Masked as _;
     pub use crate::Mask as _;
@@ -277,6 +350,7 @@ pub mod prelude {
 mod inner {
     use core::num::Wrapping;
 
+    use crate::mask::inner::Sealed;
     use crate::mask::{m128, m16, m32, m64, m8, msize, Mask};
 
     /// A trait to enable vectors to use this type as the base type.
@@ -285,121 +359,184 @@ mod inner {
     /// implementations. We make some non-trivial assumptions about the inner types and be are
     /// conservative at least until we figure out what *exact* assumptions these are and formalize
     /// them.
+    ///
+    /// # Safety
+    ///
+    /// The type must be plain old data ‒ any bit pattern of a correct size read from a vector
+    /// lane must be safe to use as the type.
     pub unsafe trait Repr: Send + Sync + Copy + 'static {
         type Mask: Mask;
         const ONE: Self;
+        /// The name of the type as used in the type aliases (eg. `wu32` for `Wrapping<u32>`).
+        const NAME: &'static str;
     }
 
     unsafe impl Repr for Wrapping<u8> {
         type Mask = m8;
         const ONE: Wrapping<u8> = Wrapping(1);
+        const NAME: &'static str = "wu8";
     }
     unsafe impl Repr for Wrapping<u16> {
         type Mask = m16;
         const ONE: Wrapping<u16> = Wrapping(1);
+        const NAME: &'static str = "wu16";
     }
     unsafe impl Repr for Wrapping<u32> {
         type Mask = m32;
         const ONE: Wrapping<u32> = Wrapping(1);
+        const NAME: &'static str = "wu32";
     }
     unsafe impl Repr for Wrapping<u64> {
         type Mask = m64;
         const ONE: Wrapping<u64> = Wrapping(1);
+        const NAME: &'static str = "wu64";
     }
     unsafe impl Repr for Wrapping<u128> {
         type Mask = m128;
         const ONE: Wrapping<u128> = Wrapping(1);
+        const NAME: &'static str = "wu128";
     }
     unsafe impl Repr for Wrapping<usize> {
         type Mask = msize;
         const ONE: Wrapping<usize> = Wrapping(1);
+        const NAME: &'static str = "wusize";
     }
     unsafe impl Repr for u8 {
         type Mask = m8;
         const ONE: u8 = 1;
+        const NAME: &'static str = "u8";
     }
     unsafe impl Repr for u16 {
         type Mask = m16;
         const ONE: u16 = 1;
+        const NAME: &'static str = "u16";
     }
     unsafe impl Repr for u32 {
         type Mask = m32;
         const ONE: u32 = 1;
+        const NAME: &'static str = "u32";
     }
     unsafe impl Repr for u64 {
         type Mask = m64;
         const ONE: u64 = 1;
+        const NAME: &'static str = "u64";
     }
     unsafe impl Repr for u128 {
         type Mask = m128;
         const ONE: u128 = 1;
+        const NAME: &'static str = "u128";
     }
     unsafe impl Repr for usize {
         type Mask = msize;
         const ONE: usize = 1;
+        const NAME: &'static str = "usize";
     }
 
     unsafe impl Repr for Wrapping<i8> {
         type Mask = m8;
         const ONE: Wrapping<i8> = Wrapping(1);
+        const NAME: &'static str = "wi8";
     }
     unsafe impl Repr for Wrapping<i16> {
         type Mask = m16;
         const ONE: Wrapping<i16> = Wrapping(1);
+        const NAME: &'static str = "wi16";
     }
     unsafe impl Repr for Wrapping<i32> {
         type Mask = m32;
         const ONE: Wrapping<i32> = Wrapping(1);
+        const NAME: &'static str = "wi32";
     }
     unsafe impl Repr for Wrapping<i64> {
         type Mask = m64;
         const ONE: Wrapping<i64> = Wrapping(1);
+        const NAME: &'static str = "wi64";
     }
     unsafe impl Repr for Wrapping<i128> {
         type Mask = m128;
         const ONE: Wrapping<i128> = Wrapping(1);
+        const NAME: &'static str = "wi128";
     }
     unsafe impl Repr for Wrapping<isize> {
         type Mask = msize;
         const ONE: Wrapping<isize> = Wrapping(1);
+        const NAME: &'static str = "wisize";
     }
     unsafe impl Repr for i8 {
         type Mask = m8;
         const ONE: i8 = 1;
+        const NAME: &'static str = "i8";
     }
     unsafe impl Repr for i16 {
         type Mask = m16;
         const ONE: i16 = 1;
+        const NAME: &'static str = "i16";
     }
     unsafe impl Repr for i32 {
         type Mask = m32;
         const ONE: i32 = 1;
+        const NAME: &'static str = "i32";
     }
     unsafe impl Repr for i64 {
         type Mask = m64;
         const ONE: i64 = 1;
+        const NAME: &'static str = "i64";
     }
     unsafe impl Repr for i128 {
         type Mask = m128;
         const ONE: i128 = 1;
+        const NAME: &'static str = "i128";
     }
     unsafe impl Repr for isize {
         type Mask = msize;
         const ONE: isize = 1;
+        const NAME: &'static str = "isize";
     }
 
     unsafe impl Repr for f32 {
         type Mask = m32;
         const ONE: f32 = 1.0;
+        const NAME: &'static str = "f32";
     }
     unsafe impl Repr for f64 {
         type Mask = m64;
         const ONE: f64 = 1.0;
+        const NAME: &'static str = "f64";
     }
     unsafe impl<M: Mask> Repr for M {
         type Mask = Self;
         const ONE: M = M::TRUE;
+        const NAME: &'static str = <M as Sealed>::NAME;
+    }
+
+    /// Conversion between the primitive numeric types.
+    ///
+    /// This does the same as the `as` operator. In particular, conversions from floats to
+    /// integers round towards zero and saturate on the ends of the range (NaN is converted to
+    /// `0`).
+    pub trait Cast<T>: Copy {
+        fn cast(self) -> T;
+    }
+
+    macro_rules! cast {
+        ($($from: ty),*) => {
+            $(
+                cast!(@to $from => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+            )*
+        };
+        (@to $from: ty => $($to: ty),*) => {
+            $(
+                impl Cast<$to> for $from {
+                    #[inline(always)]
+                    fn cast(self) -> $to {
+                        self as $to
+                    }
+                }
+            )*
+        };
     }
+
+    cast!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 }
 
 /// Free-standing version of [`Vectorizable::vectorize`].
@@ -440,6 +577,98 @@ where
     a.vectorize_pad(pad)
 }
 
+/// Vectorizes every `stride`-th element of a slice.
+///
+/// This is a shorthand for `Strided::new(data, stride).vectorize()`, see
+/// [`Strided`][iterators::Strided] for the details (and for the padded versions).
+///
+/// # Panics
+///
+/// If the `stride` is 0 or if the number of the taken elements is not divisible by the number of
+/// lanes.
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// // x and y coordinates, interleaved
+/// let data = [1, 10, 2, 20, 3, 30, 4, 40];
+/// let xs = slipstream::vectorize_strided(&data[..], 2).collect::<Vec<u32x4>>();
+/// assert_eq!(xs, vec![u32x4::new([1, 2, 3, 4])]);
+/// ```
+#[inline(always)]
+pub fn vectorize_strided<'a, A, B, const S: usize>(
+    data: &'a [B],
+    stride: usize,
+) -> impl DoubleEndedIterator<Item = Vector<A, B, S>> + ExactSizeIterator + 'a
+where
+    A: vector::align::Align + 'a,
+    B: inner::Repr + 'a,
+{
+    Vectorizable::<Vector<A, B, S>>::vectorize(iterators::Strided::new(data, stride))
+}
+
+/// Vectorizes a fixed-sized array, checking at compile time that it splits into whole vectors.
+///
+/// This is the same as `a.vectorize()`, but while that panics at runtime if the length of the
+/// array is not divisible by the number of lanes, this fails to compile. The check can't be done
+/// in [`Vectorizable::vectorize`] itself, because the same implementation serves
+/// [`vectorize_pad`][Vectorizable::vectorize_pad], where such arrays are fine.
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// let data = [1, 2, 3, 4, 5, 6, 7, 8];
+/// let v = slipstream::vectorize_array(&data).collect::<Vec<u32x4>>();
+/// assert_eq!(v, vec![u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])]);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use slipstream::prelude::*;
+/// let data = [1, 2, 3, 4, 5, 6];
+/// let v = slipstream::vectorize_array(&data).collect::<Vec<u32x4>>();
+/// ```
+#[inline(always)]
+pub fn vectorize_array<'a, A, B, const S: usize, const N: usize>(
+    a: &'a [B; N],
+) -> impl DoubleEndedIterator<Item = Vector<A, B, S>> + ExactSizeIterator + 'a
+where
+    A: vector::align::Align + 'a,
+    B: inner::Repr + 'a,
+{
+    const {
+        assert!(
+            N.is_multiple_of(S),
+            "Array length not divisible by the number of lanes"
+        )
+    };
+    Vectorizable::<Vector<A, B, S>>::vectorize(a)
+}
+
+/// Mutable version of [`vectorize_array`].
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// let mut data = [1, 2, 3, 4, 5, 6, 7, 8];
+/// for mut v in slipstream::vectorize_array_mut(&mut data) {
+///     *v = u32x4::splat(2) * *v;
+/// }
+/// assert_eq!(data, [2, 4, 6, 8, 10, 12, 14, 16]);
+/// ```
+#[inline(always)]
+pub fn vectorize_array_mut<'a, A, B, const S: usize, const N: usize>(
+    a: &'a mut [B; N],
+) -> impl DoubleEndedIterator<Item = iterators::MutProxy<'a, B, Vector<A, B, S>>> + ExactSizeIterator
+where
+    A: vector::align::Align + 'a,
+    B: inner::Repr + 'a,
+{
+    const {
+        assert!(
+            N.is_multiple_of(S),
+            "Array length not divisible by the number of lanes"
+        )
+    };
+    Vectorizable::<iterators::MutProxy<'a, B, Vector<A, B, S>>>::vectorize(a)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -455,4 +684,18 @@ mod tests {
         assert_eq!(a.maximum(b), b.maximum(a));
         assert_eq!(a.maximum(b).ge(a.minimum(b)), m32x4::splat(m32::TRUE));
     }
+
+    #[test]
+    fn vectorize_array() {
+        let data = [1u16, 2, 3, 4, 5, 6, 7, 8];
+        let v = crate::vectorize_array(&data).rev().collect::<Vec<u16x4>>();
+        assert_eq!(v, [u16x4::new([5, 6, 7, 8]), u16x4::new([1, 2, 3, 4])]);
+
+        let mut out = [0u16; 8];
+        for (mut o, i) in crate::vectorize_array_mut(&mut out).zip(crate::vectorize_array(&data)) {
+            let i: u16x8 = i;
+            *o = i + 1;
+        }
+        assert_eq!(out, [2, 3, 4, 5, 6, 7, 8, 9]);
+    }
 }
@@ -15,7 +15,7 @@
 //!
 //! The interesting operations are:
 //! * Comparisons ([`lt`][crate::Vector::lt], [`le`][crate::Vector::le], [`eq`][crate::Vector::eq],
-//!   [`ge`][crate::Vector::ge], [`gt`][crate::Vector::gt])
+//!   [`ne`][crate::Vector::ne], [`ge`][crate::Vector::ge], [`gt`][crate::Vector::gt])
 //! * The [`blend`][crate::Vector::blend] method.
 //! * Masked [loading][crate::Vector::gather_load_masked] and
 //!   [storing][crate::Vector::scatter_store_masked] of vectors.
@@ -45,10 +45,14 @@
 //! abs(&mut data);
 //! assert_eq!(data, [1, 2, 3]);
 //! ```
+use core::fmt::{Formatter, LowerHex, Result as FmtResult, UpperHex};
 use core::ops::*;
 
-mod inner {
-    pub trait Sealed {}
+pub(crate) mod inner {
+    pub trait Sealed {
+        /// The name used in the type aliases, like `m32` (or `b` for bool).
+        const NAME: &'static str;
+    }
 }
 
 /// The trait implemented by all the mask types.
@@ -141,10 +145,24 @@ impl<I: Not<Output = I>> Not for MaskWrapper<I> {
     }
 }
 
+impl<I: LowerHex> LowerHex for MaskWrapper<I> {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        self.0.fmt(fmt)
+    }
+}
+
+impl<I: UpperHex> UpperHex for MaskWrapper<I> {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        self.0.fmt(fmt)
+    }
+}
+
 #[allow(missing_docs)]
 pub type m8 = MaskWrapper<u8>;
 
-impl inner::Sealed for m8 {}
+impl inner::Sealed for m8 {
+    const NAME: &'static str = "m8";
+}
 
 impl Mask for m8 {
     const TRUE: Self = MaskWrapper(u8::MAX);
@@ -154,7 +172,9 @@ impl Mask for m8 {
 #[allow(missing_docs)]
 pub type m16 = MaskWrapper<u16>;
 
-impl inner::Sealed for m16 {}
+impl inner::Sealed for m16 {
+    const NAME: &'static str = "m16";
+}
 
 impl Mask for m16 {
     const TRUE: Self = MaskWrapper(u16::MAX);
@@ -164,7 +184,9 @@ impl Mask for m16 {
 #[allow(missing_docs)]
 pub type m32 = MaskWrapper<u32>;
 
-impl inner::Sealed for m32 {}
+impl inner::Sealed for m32 {
+    const NAME: &'static str = "m32";
+}
 
 impl Mask for m32 {
     const TRUE: Self = MaskWrapper(u32::MAX);
@@ -174,7 +196,9 @@ impl Mask for m32 {
 #[allow(missing_docs)]
 pub type m64 = MaskWrapper<u64>;
 
-impl inner::Sealed for m64 {}
+impl inner::Sealed for m64 {
+    const NAME: &'static str = "m64";
+}
 
 impl Mask for m64 {
     const TRUE: Self = MaskWrapper(u64::MAX);
@@ -184,7 +208,9 @@ impl Mask for m64 {
 #[allow(missing_docs)]
 pub type m128 = MaskWrapper<u128>;
 
-impl inner::Sealed for m128 {}
+impl inner::Sealed for m128 {
+    const NAME: &'static str = "m128";
+}
 
 impl Mask for m128 {
     const TRUE: Self = MaskWrapper(u128::MAX);
@@ -194,14 +220,39 @@ impl Mask for m128 {
 #[allow(missing_docs)]
 pub type msize = MaskWrapper<usize>;
 
-impl inner::Sealed for msize {}
+impl inner::Sealed for msize {
+    const NAME: &'static str = "msize";
+}
 
 impl Mask for msize {
     const TRUE: Self = MaskWrapper(usize::MAX);
     const FALSE: Self = MaskWrapper(0);
 }
 
-impl inner::Sealed for bool {}
+// Masks are serialized as bools. That also guarantees we get only valid values on deserialization.
+#[cfg(feature = "serde")]
+impl<I> serde::Serialize for MaskWrapper<I>
+where
+    Self: Mask,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(self.bool())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I> serde::Deserialize<'de> for MaskWrapper<I>
+where
+    Self: Mask,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bool::deserialize(deserializer).map(Self::from_bool)
+    }
+}
+
+impl inner::Sealed for bool {
+    const NAME: &'static str = "b";
+}
 
 impl Mask for bool {
     const TRUE: Self = true;
@@ -197,3 +197,82 @@ mod sized {
 }
 
 pub use sized::*;
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use core::mem;
+    use core::ops::Deref;
+
+    use bytemuck::{Pod, Zeroable};
+
+    use super::*;
+    use crate::inner::Repr;
+    use crate::mask::MaskWrapper;
+
+    // All zeroes is a valid value for anything, including the padding, if any.
+    unsafe impl<A: Align, B: Repr + Zeroable, const S: usize> Zeroable for Vector<A, B, S> {}
+    // All zeroes is the false value.
+    unsafe impl<I: Zeroable> Zeroable for MaskWrapper<I> {}
+
+    // Not done generically, as some combinations of alignment and length have padding. The ones
+    // here don't, which is checked at compile time.
+    macro_rules! pod {
+        ($($t: ty),* $(,)?) => {
+            $(
+                const _: () = assert!(
+                    mem::size_of::<$t>() == mem::size_of::<<$t as Deref>::Target>(),
+                    "Vector type with padding",
+                );
+                unsafe impl Pod for $t {}
+            )*
+        };
+    }
+
+    pod!(u8x2, u8x4, u8x8, u8x16, u8x32);
+    pod!(u16x2, u16x4, u16x8, u16x16);
+    pod!(u32x2, u32x4, u32x8, u32x16);
+    pod!(u64x2, u64x4, u64x8, u64x16);
+    pod!(wu8x2, wu8x4, wu8x8, wu8x16, wu8x32);
+    pod!(wu16x2, wu16x4, wu16x8, wu16x16);
+    pod!(wu32x2, wu32x4, wu32x8, wu32x16);
+    pod!(wu64x2, wu64x4, wu64x8, wu64x16);
+    pod!(i8x2, i8x4, i8x8, i8x16, i8x32);
+    pod!(i16x2, i16x4, i16x8, i16x16);
+    pod!(i32x2, i32x4, i32x8, i32x16);
+    pod!(i64x2, i64x4, i64x8, i64x16);
+    pod!(wi8x2, wi8x4, wi8x8, wi8x16, wi8x32);
+    pod!(wi16x2, wi16x4, wi16x8, wi16x16);
+    pod!(wi32x2, wi32x4, wi32x8, wi32x16);
+    pod!(wi64x2, wi64x4, wi64x8, wi64x16);
+    pod!(f32x2, f32x4, f32x8, f32x16);
+    pod!(f64x2, f64x4, f64x8, f64x16);
+    pod!(usizex2, usizex4, usizex8, usizex16);
+    pod!(wusizex2, wusizex4, wusizex8, wusizex16);
+    pod!(isizex2, isizex4, isizex8, isizex16);
+    pod!(wisizex2, wisizex4, wisizex8, wisizex16);
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::Mask;
+
+        #[test]
+        fn cast_slices() {
+            let vectors = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+            let bytes: &[u8] = bytemuck::cast_slice(&vectors);
+            assert_eq!(bytes.len(), 32);
+            for (i, chunk) in bytes.chunks(4).enumerate() {
+                assert_eq!(chunk, (i as u32 + 1).to_ne_bytes());
+            }
+
+            let back: &[u32x4] = bytemuck::cast_slice(bytes);
+            assert_eq!(back, vectors);
+        }
+
+        #[test]
+        fn zeroed() {
+            assert_eq!(f32x8::zeroed(), f32x8::default());
+            assert_eq!(m16x4::zeroed(), m16x4::splat(m16::FALSE));
+        }
+    }
+}
@@ -17,15 +17,33 @@
 //! These types aliases are not thoroughly documented on themselves. The documentation is on the
 //! [`Vector`]. A lot of its functionality is in traits it implements.
 
-use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
+use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::mem::{self, MaybeUninit};
 use core::ops::*;
 use core::ptr;
 
 use self::align::Align;
-use crate::inner::Repr;
-use crate::Mask;
+use crate::inner::{Cast, Repr};
+use crate::{Mask, Vectorizable};
+
+#[cfg(feature = "approx")]
+mod approx;
+mod float;
+mod halves;
+mod int;
+mod math;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "serde")]
+mod serde;
 
 /// Enforcement of alignment.
 ///
@@ -80,6 +98,71 @@ pub trait Masked {
     type Mask;
 }
 
+/// A compile-time description of a lane permutation of single vector.
+///
+/// This is used by [`Vector::swizzle`]. Each lane of the output is taken from the input lane at
+/// the corresponding position in [`INDICES`][Swizzle::INDICES]. The indices are checked at
+/// compile time, therefore a permutation with an out of bounds lane doesn't compile.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// use slipstream::vector::Swizzle;
+///
+/// struct Pairs;
+///
+/// impl Swizzle<4> for Pairs {
+///     const INDICES: [usize; 4] = [0, 0, 2, 2];
+/// }
+///
+/// let v = u32x4::new([1, 2, 3, 4]);
+/// assert_eq!(v.swizzle::<Pairs>(), u32x4::new([1, 1, 3, 3]));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use slipstream::prelude::*;
+/// # use slipstream::vector::Swizzle;
+/// struct OutOfBounds;
+///
+/// impl Swizzle<4> for OutOfBounds {
+///     const INDICES: [usize; 4] = [0, 1, 2, 4];
+/// }
+///
+/// u32x4::splat(1).swizzle::<OutOfBounds>();
+/// ```
+pub trait Swizzle<const S: usize> {
+    /// The source lane for each lane of the output.
+    const INDICES: [usize; S];
+}
+
+/// A compile-time description of a lane permutation of two vectors.
+///
+/// This is similar to [`Swizzle`], but used with [`Vector::shuffle2`]. The indices address
+/// lanes of both vectors concatenated together ‒ the indices smaller than the number of lanes
+/// take from the first vector, the rest from the second one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// use slipstream::vector::Swizzle2;
+///
+/// struct InterleaveLow;
+///
+/// impl Swizzle2<4> for InterleaveLow {
+///     const INDICES: [usize; 4] = [0, 4, 1, 5];
+/// }
+///
+/// let a = u32x4::new([1, 2, 3, 4]);
+/// let b = u32x4::new([5, 6, 7, 8]);
+/// assert_eq!(a.shuffle2::<InterleaveLow>(b), u32x4::new([1, 5, 2, 6]));
+/// ```
+pub trait Swizzle2<const S: usize> {
+    /// The source lane for each lane of the output.
+    const INDICES: [usize; S];
+}
+
 macro_rules! bin_op_impl {
     ($tr: ident, $meth: ident, $tr_assign: ident, $meth_assign: ident) => {
         impl<A: Align, B: $tr<Output = B> + Repr, const S: usize> $tr for Vector<A, B, S> {
@@ -181,6 +264,30 @@ macro_rules! cmp_op {
     };
 }
 
+/// Scalar maximum with the IEEE `maxNum` semantics.
+///
+/// If one of the values is NaN (the only value not comparable to itself), the other is returned.
+#[inline(always)]
+fn max_num<B: PartialOrd>(a: B, b: B) -> B {
+    if a < b || a.partial_cmp(&a).is_none() {
+        b
+    } else {
+        a
+    }
+}
+
+/// Scalar minimum with the IEEE `minNum` semantics.
+///
+/// See [`max_num`].
+#[inline(always)]
+fn min_num<B: PartialOrd>(a: B, b: B) -> B {
+    if b < a || a.partial_cmp(&a).is_none() {
+        b
+    } else {
+        a
+    }
+}
+
 /// A vector type.
 ///
 /// Vector types are mostly well aligned fixed sized arrays. Unlike the arrays, they have the usual
@@ -193,11 +300,43 @@ macro_rules! cmp_op {
 /// While these can be operated as arrays (indexing, copying between slices, etc), it is better to
 /// perform operations on whole vectors at once.
 ///
-/// The usual comparing operators don't exist (`<=`), but there are „per lane“ comparison operators
-/// that return mask vectors ‒ vectors of boolean-like values. These can either be examined
-/// manually, or fed into other operations on vectors, like [`blend`][Vector::blend] or
+/// The arithmetic operators (`+`, `-`, `*`, `/`, …) are applied to each lane separately, with the
+/// right hand side being either another vector or a single scalar (which is then used for all the
+/// lanes). Each lane behaves exactly like the base type would. In particular, overflow of the
+/// plain integer types panics in debug builds and wraps in release builds, exactly like the
+/// scalars do. If wrapping is desired, use the vectors based on [`Wrapping`][core::num::Wrapping]
+/// types (eg. [`wu32x4`][crate::wu32x4]), which always wrap. Integer division by zero panics.
+///
+/// Similarly, the unary operators are available whenever the base type has them. Therefore the
+/// negation is available on the signed and floating point vectors, but not on the unsigned ones:
+///
+/// ```rust,compile_fail
+/// # use slipstream::prelude::*;
+/// let v = -u32x4::splat(1);
+/// ```
+///
+/// The bitwise operators (`&`, `|`, `^`, `!`) are implemented for the integer (and mask)
+/// vectors, but not for the floating point ones. The shifts (`<<`, `>>`) are available for the
+/// integer vectors and take the shift amount either as a scalar (shifting all the lanes by the
+/// same amount) or another vector (shifting each lane separately). As with scalars, the right
+/// shift is arithmetic for signed and logical for unsigned types and shifting by the bit width or
+/// more is an overflow.
+///
+/// There are „per lane“ comparison methods (like [`lt`][Vector::lt]) that return mask vectors ‒
+/// vectors of boolean-like values. These can either be examined manually, or fed into other
+/// operations on vectors, like [`blend`][Vector::blend] or
 /// [`gather_load_masked`][Vector::gather_load_masked].
 ///
+/// On the other hand, the `==` operator ([`PartialEq`]) compares whole vectors, lane by lane, and
+/// returns a single `bool`. The integer vectors (and the masks) are also [`Eq`] and [`Hash`], so
+/// they can be used as keys in hash maps. The floating point vectors are only [`PartialEq`], for
+/// the same reasons as the floating point scalars are (NaN is not equal to itself).
+///
+/// Similarly, the `<` and similar operators ([`PartialOrd`]) and [`Ord`] compare whole vectors
+/// *lexicographically*, the first lane being the most significant (the same as arrays are
+/// compared). This is not a lane-wise comparison, but it allows sorting the vectors or using
+/// them as keys in ordered maps. The floating point vectors are only [`PartialOrd`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -229,19 +368,74 @@ where
     pub const LANES: usize = S;
 
     #[inline(always)]
-    fn assert_size() {
+    pub(crate) const fn assert_size() {
         assert!(S > 0);
         assert!(
             isize::MAX as usize > mem::size_of::<Self>(),
             "Vector type too huge",
         );
-        assert_eq!(
-            mem::size_of::<Self>(),
-            mem::size_of::<[B; S]>(),
+        // Not assert_eq, that one can't be used in const fn
+        assert!(
+            mem::size_of::<Self>() == mem::size_of::<[B; S]>(),
             "Must not contain paddings/invalid Align parameter",
         );
     }
 
+    /// Applies the function to each lane, producing a new vector.
+    ///
+    /// This is an escape hatch for operations the vectors don't provide (eg. a table lookup).
+    /// Simple functions might still get auto-vectorized, but usually this processes the lanes
+    /// one by one. The function is called for the lanes in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// const TABLE: [u8; 4] = [10, 20, 30, 40];
+    /// let v = u8x4::new([3, 0, 2, 2]).map_lanes(|l| TABLE[l as usize]);
+    /// assert_eq!(v, u8x4::new([40, 10, 30, 30]));
+    /// ```
+    #[inline(always)]
+    pub fn map_lanes<F: FnMut(B) -> B>(self, mut f: F) -> Self {
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(data.as_mut_ptr().cast::<B>().add(i), f(self.data[i]));
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Combines lanes of two vectors by the function, producing a new vector.
+    ///
+    /// This is the binary counterpart of [`map_lanes`][Vector::map_lanes], with the same
+    /// performance caveats. The function gets the corresponding lanes of `self` and `other` and
+    /// is called for the lanes in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let a = u8x4::new([200, 100, 0, 255]);
+    /// let b = u8x4::new([100, 100, 1, 255]);
+    /// // Average without overflowing
+    /// let avg = a.zip_lanes(b, |a, b| ((a as u16 + b as u16) / 2) as u8);
+    /// assert_eq!(avg, u8x4::new([150, 100, 0, 255]));
+    /// ```
+    #[inline(always)]
+    pub fn zip_lanes<F: FnMut(B, B) -> B>(self, other: Self, mut f: F) -> Self {
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(
+                    data.as_mut_ptr().cast::<B>().add(i),
+                    f(self.data[i], other.data[i]),
+                );
+            }
+            data.assume_init()
+        }
+    }
+
     /// Loads the vector without doing bounds checks.
     ///
     /// # Safety
@@ -291,16 +485,35 @@ where
         unsafe { Self::new_unchecked(input.as_ptr()) }
     }
 
-    // TODO: Can we turn it into const fn?
+    /// Loads the vector from an array.
+    ///
+    /// Unlike [`new`][Vector::new], this is a `const fn`, so it can be used to define constants.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// const COEFFS: f32x4 = f32x4::from_array([1.0, 0.5, 0.25, 0.125]);
+    /// assert_eq!(COEFFS * 2.0, f32x4::new([2.0, 1.0, 0.5, 0.25]));
+    /// ```
+    #[inline]
+    pub const fn from_array(data: [B; S]) -> Self {
+        Self::assert_size();
+        Self { _align: [], data }
+    }
+
     /// Produces a vector of all lanes set to the same value.
     ///
+    /// This is a `const fn`, so it can be used to define constants.
+    ///
     /// ```rust
     /// # use slipstream::prelude::*;
     /// let v = f32x4::splat(1.2);
     /// assert_eq!(v, f32x4::new([1.2, 1.2, 1.2, 1.2]));
+    ///
+    /// const ONES: u32x4 = u32x4::splat(1);
+    /// assert_eq!(ONES + ONES, u32x4::splat(2));
     /// ```
     #[inline]
-    pub fn splat(value: B) -> Self {
+    pub const fn splat(value: B) -> Self {
         Self::assert_size();
         Self {
             _align: [],
@@ -308,375 +521,1366 @@ where
         }
     }
 
-    /// Loads the vector from a slice by indexing it.
-    ///
-    /// Unlike [`new`], this can load the vector from discontinuous parts of the slice, out of
-    /// order or multiple lanes from the same location. This flexibility comes at the cost of lower
-    /// performance (in particular, I've never seen this to get auto-vectorized even though a
-    /// gather instruction exists), therefore prefer [`new`] where possible.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use slipstream::prelude::*;
-    /// let input = (2..100).collect::<Vec<_>>();
-    /// let vec = u32x4::gather_load(&input, [3, 3, 1, 32]);
-    /// assert_eq!(vec, u32x4::new([5, 5, 3, 34]));
-    /// ```
-    ///
-    /// It is possible to use another vector as the indices:
-    ///
-    /// ```rust
-    /// # use slipstream::prelude::*;
-    /// let indices = usizex4::new([1, 2, 3, 4]) * usizex4::splat(2);
-    /// let input = (0..10).collect::<Vec<_>>();
-    /// let vec = u32x4::gather_load(&input, indices);
-    /// assert_eq!(vec, u32x4::new([2, 4, 6, 8]));
-    /// ```
+    /// Produces a vector with each lane computed by a closure from its index.
     ///
-    /// It is possible to use another vector as an input, allowing to narrow it down or shuffle.
+    /// This is the vector counterpart of [`core::array::from_fn`]. The closure is called for the
+    /// lanes in order.
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let a = u32x4::new([1, 2, 3, 4]);
-    /// let b = u32x4::gather_load(a, [2, 0, 1, 3]);
-    /// assert_eq!(b, u32x4::new([3, 1, 2, 4]));
-    /// let c = u32x2::gather_load(a, [2, 2]);
-    /// assert_eq!(c, u32x2::new([3, 3]));
+    /// let v = u32x4::from_fn(|i| i as u32 * 2);
+    /// assert_eq!(v, u32x4::new([0, 2, 4, 6]));
     /// ```
-    ///
-    /// # Panics
-    ///
-    /// * If the `idx` slice doesn't have the same length as the vector.
-    /// * If any of the indices is out of bounds of the `input`.
-    ///
-    /// [`new`]: Vector::new
     #[inline]
-    pub fn gather_load<I, Idx>(input: I, idx: Idx) -> Self
-    where
-        I: AsRef<[B]>,
-        Idx: AsRef<[usize]>,
-    {
+    pub fn from_fn<F: FnMut(usize) -> B>(mut f: F) -> Self {
         Self::assert_size();
-        let input = input.as_ref();
-        let idx = idx.as_ref();
-        assert_eq!(
-            S,
-            idx.len(),
-            "Gathering vector from wrong number of indexes"
-        );
-        assert!(idx.iter().all(|&l| l < input.len()), "Gather out of bounds");
         let mut data = MaybeUninit::<Self>::uninit();
         unsafe {
             for i in 0..S {
-                let idx = *idx.get_unchecked(i);
-                let input = *input.get_unchecked(idx);
-                ptr::write(data.as_mut_ptr().cast::<B>().add(i), input);
+                ptr::write(data.as_mut_ptr().cast::<B>().add(i), f(i));
             }
             data.assume_init()
         }
     }
 
-    /// Loads enabled lanes from a slice by indexing it.
+    /// Returns the value of a single lane.
     ///
-    /// This is similar to [`gather_load`]. However, the loading of lanes is
-    /// enabled by a mask. If the corresponding lane mask is not set, the value is taken from
-    /// `self`. In other words, if the mask is all-true, it is semantically equivalent to
-    /// [`gather_load`], expect with possible worse performance.
+    /// This is the same as indexing, but by value.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// # use slipstream::prelude::*;
-    /// let input = (0..100).collect::<Vec<_>>();
-    /// let v = u32x4::default().gather_load_masked(
-    ///     &input,
-    ///     [1, 4, 2, 2],
-    ///     [m32::TRUE, m32::FALSE, m32::FALSE, m32::TRUE]
-    /// );
-    /// assert_eq!(v, u32x4::new([1, 0, 0, 2]));
-    /// ```
+    /// If the `idx` is out of bounds.
+    #[inline]
+    pub fn extract(self, idx: usize) -> B {
+        assert!(idx < S, "Lane index {} out of bounds ({} lanes)", idx, S);
+        self.data[idx]
+    }
+
+    /// Returns the value of a single lane, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The `idx` must be smaller than [`LANES`][Vector::LANES].
+    #[inline]
+    pub unsafe fn extract_unchecked(self, idx: usize) -> B {
+        *self.data.get_unchecked(idx)
+    }
+
+    /// Produces a new vector with a single lane replaced by the `value`.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let left = u32x2::new([1, 2]);
-    /// let right = u32x2::new([3, 4]);
-    /// let idx = usizex4::new([0, 1, 0, 1]);
-    /// let mask = m32x4::new([m32::TRUE, m32::TRUE, m32::FALSE, m32::FALSE]);
-    /// let v = u32x4::default()
-    ///     .gather_load_masked(left, idx, mask)
-    ///     .gather_load_masked(right, idx, !mask);
-    /// assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+    /// let v = u32x4::new([1, 2, 3, 4]).replace(2, 42);
+    /// assert_eq!(v, u32x4::new([1, 2, 42, 4]));
+    /// assert_eq!(v.extract(2), 42);
     /// ```
     ///
     /// # Panics
     ///
-    /// * If the `mask` or the `idx` parameter is of different length than the vector.
-    /// * If any of the active indices are out of bounds of `input`.
+    /// If the `idx` is out of bounds.
+    #[inline]
+    pub fn replace(mut self, idx: usize, value: B) -> Self {
+        assert!(idx < S, "Lane index {} out of bounds ({} lanes)", idx, S);
+        self.data[idx] = value;
+        self
+    }
+
+    /// Produces a new vector with a single lane replaced, without bounds checking.
     ///
-    /// [`gather_load`]: Vector::gather_load
+    /// # Safety
+    ///
+    /// The `idx` must be smaller than [`LANES`][Vector::LANES].
     #[inline]
-    pub fn gather_load_masked<I, Idx, M, MB>(mut self, input: I, idx: Idx, mask: M) -> Self
-    where
-        I: AsRef<[B]>,
-        Idx: AsRef<[usize]>,
-        M: AsRef<[MB]>,
-        MB: Mask,
-    {
-        let input = input.as_ref();
-        let idx = idx.as_ref();
-        let mask = mask.as_ref();
-        let len = idx.len();
-        assert_eq!(S, len, "Gathering vector from wrong number of indexes");
-        assert_eq!(S, mask.len(), "Gathering with wrong sized mask");
-        for i in 0..S {
-            unsafe {
-                if mask.get_unchecked(i).bool() {
-                    let idx = *idx.get_unchecked(i);
-                    self[i] = input[idx];
-                }
-            }
-        }
+    pub unsafe fn replace_unchecked(mut self, idx: usize, value: B) -> Self {
+        *self.data.get_unchecked_mut(idx) = value;
         self
     }
 
-    /// Stores the content into a continuous slice of the correct length.
+    /// Produces a vector with all the lanes set to the value of a single lane.
     ///
-    /// This is less general than [`scatter_store`][Vector::scatter_store], that one allows storing
-    /// to different parts of the slice.
+    /// This is the same as `Self::splat(self.extract(idx))`.
     ///
-    /// The counterpart of this is [`new`][Vector::new].
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(v.broadcast_lane(2), f32x4::splat(3.0));
+    /// ```
     ///
     /// # Panics
     ///
-    /// If the length doesn't match.
+    /// If the `idx` is out of bounds.
     #[inline]
-    pub fn store<O: AsMut<[B]>>(self, mut output: O) {
-        output.as_mut().copy_from_slice(&self[..])
+    pub fn broadcast_lane(self, idx: usize) -> Self {
+        Self::splat(self.extract(idx))
     }
 
-    /// Store the vector into a slice by indexing it.
+    /// Returns the first lane.
     ///
-    /// This is the inverse of [`gather_load`][Vector::gather_load]. It takes the lanes of the
-    /// vector and stores them into the slice into given indices.
+    /// This is not named `first`, as that would shadow the method of slices the vector
+    /// dereferences to.
     ///
-    /// If you want to store it into a continuous slice, it is potentially faster to do it using
-    /// the `copy_from_slice` method or by [`store`][Vector::store]:
+    /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let mut data = vec![0; 6];
     /// let v = u32x4::new([1, 2, 3, 4]);
-    /// data[0..4].copy_from_slice(&v[..]);
-    /// assert_eq!(&data[..], &[1, 2, 3, 4, 0, 0]);
-    /// v.store(&mut data[..4]);
-    /// assert_eq!(&data[..], &[1, 2, 3, 4, 0, 0]);
+    /// assert_eq!((v.first_lane(), v.last_lane()), (1, 4));
     /// ```
+    #[inline]
+    pub fn first_lane(self) -> B {
+        self.extract(0)
+    }
+
+    /// Returns the last lane.
+    #[inline]
+    pub fn last_lane(self) -> B {
+        self.extract(S - 1)
+    }
+
+    /// Rotates the lanes towards the lower indices.
+    ///
+    /// The lanes shifted out at the beginning are moved to the end. The `n` is taken modulo the
+    /// number of lanes.
+    ///
+    /// Note that this moves whole lanes, not bits inside them.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let mut data = vec![0; 6];
     /// let v = u32x4::new([1, 2, 3, 4]);
-    /// v.scatter_store(&mut data, [2, 5, 0, 1]);
-    /// assert_eq!(&data[..], &[3, 4, 1, 0, 0, 2]);
+    /// assert_eq!(v.rotate_lanes_left(1), u32x4::new([2, 3, 4, 1]));
     /// ```
+    #[inline]
+    pub fn rotate_lanes_left(mut self, n: usize) -> Self {
+        self.data.rotate_left(n % S);
+        self
+    }
+
+    /// Rotates the lanes towards the higher indices.
     ///
-    /// # Warning
-    ///
-    /// If multiple lanes are to be stored into the same slice element, it is not specified which
-    /// of them will end up being stored. It is not UB to do so and it'll always be one of them,
-    /// however it may change between versions or even between compilation targets which.
-    ///
-    /// This is to allow for potential different behaviour of different platforms.
+    /// The lanes shifted out at the end are moved to the beginning. The `n` is taken modulo the
+    /// number of lanes.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// * If the `idx` has a different length than the vector.
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// assert_eq!(v.rotate_lanes_right(1), u32x4::new([4, 1, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn rotate_lanes_right(mut self, n: usize) -> Self {
+        self.data.rotate_right(n % S);
+        self
+    }
+
+    /// Reverses the order of lanes.
+    ///
+    /// Note that due to dereferencing to an array, the vector also has the in-place `reverse`
+    /// method of slices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// assert_eq!(v.reverse_lanes(), u32x4::new([4, 3, 2, 1]));
+    /// ```
+    #[inline]
+    pub fn reverse_lanes(self) -> Self {
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(data.as_mut_ptr().cast::<B>().add(i), self.data[S - 1 - i]);
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Reorders the lanes according to a permutation known at compile time.
+    ///
+    /// The permutation can duplicate or leave out lanes. See [`Swizzle`] for details and
+    /// examples.
+    #[inline]
+    pub fn swizzle<P: Swizzle<S>>(self) -> Self {
+        const {
+            let mut i = 0;
+            while i < S {
+                assert!(P::INDICES[i] < S, "Swizzle index out of bounds");
+                i += 1;
+            }
+        }
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(
+                    data.as_mut_ptr().cast::<B>().add(i),
+                    *self.data.get_unchecked(P::INDICES[i]),
+                );
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Picks lanes from two vectors according to a permutation known at compile time.
+    ///
+    /// See [`Swizzle2`] for details and examples.
+    #[inline]
+    pub fn shuffle2<P: Swizzle2<S>>(self, other: Self) -> Self {
+        const {
+            let mut i = 0;
+            while i < S {
+                assert!(P::INDICES[i] < 2 * S, "Swizzle index out of bounds");
+                i += 1;
+            }
+        }
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                let idx = P::INDICES[i];
+                let lane = if idx < S {
+                    *self.data.get_unchecked(idx)
+                } else {
+                    *other.data.get_unchecked(idx - S)
+                };
+                ptr::write(data.as_mut_ptr().cast::<B>().add(i), lane);
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Interleaves lanes of two vectors.
+    ///
+    /// Conceptually, this produces a double-sized vector `[self[0], other[0], self[1], other[1],
+    /// …]` and returns its low and high half. This is the „zip“ of lanes, useful for example to
+    /// produce interleaved stereo audio samples or complex numbers.
+    ///
+    /// The inverse is [`deinterleave`][Vector::deinterleave].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let re = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+    /// let im = f32x4::new([5.0, 6.0, 7.0, 8.0]);
+    /// let (lo, hi) = re.interleave(im);
+    /// assert_eq!(lo, f32x4::new([1.0, 5.0, 2.0, 6.0]));
+    /// assert_eq!(hi, f32x4::new([3.0, 7.0, 4.0, 8.0]));
+    /// ```
+    #[inline]
+    pub fn interleave(self, other: Self) -> (Self, Self) {
+        let mut lo = self;
+        let mut hi = self;
+        for i in 0..2 * S {
+            let lane = if i % 2 == 0 {
+                self.data[i / 2]
+            } else {
+                other.data[i / 2]
+            };
+            if i < S {
+                lo.data[i] = lane;
+            } else {
+                hi.data[i - S] = lane;
+            }
+        }
+        (lo, hi)
+    }
+
+    /// Splits interleaved lanes into two vectors.
+    ///
+    /// This treats `self` and `other` as a single double-sized vector and puts the lanes at even
+    /// positions into the first vector and the lanes at odd positions into the second one. This
+    /// is the inverse of [`interleave`][Vector::interleave].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let lo = f32x4::new([1.0, 5.0, 2.0, 6.0]);
+    /// let hi = f32x4::new([3.0, 7.0, 4.0, 8.0]);
+    /// let (re, im) = lo.deinterleave(hi);
+    /// assert_eq!(re, f32x4::new([1.0, 2.0, 3.0, 4.0]));
+    /// assert_eq!(im, f32x4::new([5.0, 6.0, 7.0, 8.0]));
+    /// ```
+    #[inline]
+    pub fn deinterleave(self, other: Self) -> (Self, Self) {
+        let mut even = self;
+        let mut odd = self;
+        for i in 0..2 * S {
+            let lane = if i < S {
+                self.data[i]
+            } else {
+                other.data[i - S]
+            };
+            if i % 2 == 0 {
+                even.data[i / 2] = lane;
+            } else {
+                odd.data[i / 2] = lane;
+            }
+        }
+        (even, odd)
+    }
+
+    /// Lane-wise numeric conversion to a vector of different base type.
+    ///
+    /// Each lane is converted as if by the `as` operator. The number of lanes stays the same. In
+    /// particular, conversion from floating point to integer types rounds towards zero and
+    /// saturates values that are out of range (and NaN is turned into `0`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let f = f32x4::new([1.7, -2.5, 1e20, f32::NAN]);
+    /// let i: i32x4 = f.cast();
+    /// assert_eq!(i, i32x4::new([1, -2, i32::MAX, 0]));
+    /// let back: f32x4 = i.cast();
+    /// assert_eq!(back, f32x4::new([1.0, -2.0, i32::MAX as f32, 0.0]));
+    /// ```
+    #[inline]
+    pub fn cast<AO, BO>(self) -> Vector<AO, BO, S>
+    where
+        AO: Align,
+        BO: Repr,
+        B: Cast<BO>,
+    {
+        let mut data = MaybeUninit::<Vector<AO, BO, S>>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(data.as_mut_ptr().cast::<BO>().add(i), self.data[i].cast());
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Loads the vector from a slice by indexing it.
+    ///
+    /// Unlike [`new`], this can load the vector from discontinuous parts of the slice, out of
+    /// order or multiple lanes from the same location. This flexibility comes at the cost of lower
+    /// performance (in particular, I've never seen this to get auto-vectorized even though a
+    /// gather instruction exists), therefore prefer [`new`] where possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let input = (2..100).collect::<Vec<_>>();
+    /// let vec = u32x4::gather_load(&input, [3, 3, 1, 32]);
+    /// assert_eq!(vec, u32x4::new([5, 5, 3, 34]));
+    /// ```
+    ///
+    /// It is possible to use another vector as the indices:
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let indices = usizex4::new([1, 2, 3, 4]) * usizex4::splat(2);
+    /// let input = (0..10).collect::<Vec<_>>();
+    /// let vec = u32x4::gather_load(&input, indices);
+    /// assert_eq!(vec, u32x4::new([2, 4, 6, 8]));
+    /// ```
+    ///
+    /// It is possible to use another vector as an input, allowing to narrow it down or shuffle.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let a = u32x4::new([1, 2, 3, 4]);
+    /// let b = u32x4::gather_load(a, [2, 0, 1, 3]);
+    /// assert_eq!(b, u32x4::new([3, 1, 2, 4]));
+    /// let c = u32x2::gather_load(a, [2, 2]);
+    /// assert_eq!(c, u32x2::new([3, 3]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// * If the `idx` slice doesn't have the same length as the vector.
+    /// * If any of the indices is out of bounds of the `input`.
+    ///
+    /// [`new`]: Vector::new
+    #[inline]
+    pub fn gather_load<I, Idx>(input: I, idx: Idx) -> Self
+    where
+        I: AsRef<[B]>,
+        Idx: AsRef<[usize]>,
+    {
+        Self::assert_size();
+        let input = input.as_ref();
+        let idx = idx.as_ref();
+        assert_eq!(
+            S,
+            idx.len(),
+            "Gathering vector from wrong number of indexes"
+        );
+        for &l in idx {
+            assert!(
+                l < input.len(),
+                "Gather index {} out of bounds (len {})",
+                l,
+                input.len()
+            );
+        }
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                let idx = *idx.get_unchecked(i);
+                let input = *input.get_unchecked(idx);
+                ptr::write(data.as_mut_ptr().cast::<B>().add(i), input);
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Loads enabled lanes from a slice by indexing it.
+    ///
+    /// This is similar to [`gather_load`]. However, the loading of lanes is
+    /// enabled by a mask. If the corresponding lane mask is not set, the value is taken from
+    /// `self`. In other words, if the mask is all-true, it is semantically equivalent to
+    /// [`gather_load`], expect with possible worse performance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let input = (0..100).collect::<Vec<_>>();
+    /// let v = u32x4::default().gather_load_masked(
+    ///     &input,
+    ///     [1, 4, 2, 2],
+    ///     [m32::TRUE, m32::FALSE, m32::FALSE, m32::TRUE]
+    /// );
+    /// assert_eq!(v, u32x4::new([1, 0, 0, 2]));
+    /// ```
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let left = u32x2::new([1, 2]);
+    /// let right = u32x2::new([3, 4]);
+    /// let idx = usizex4::new([0, 1, 0, 1]);
+    /// let mask = m32x4::new([m32::TRUE, m32::TRUE, m32::FALSE, m32::FALSE]);
+    /// let v = u32x4::default()
+    ///     .gather_load_masked(left, idx, mask)
+    ///     .gather_load_masked(right, idx, !mask);
+    /// assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// * If the `mask` or the `idx` parameter is of different length than the vector.
+    /// * If any of the active indices are out of bounds of `input`.
+    ///
+    /// [`gather_load`]: Vector::gather_load
+    #[inline]
+    pub fn gather_load_masked<I, Idx, M, MB>(mut self, input: I, idx: Idx, mask: M) -> Self
+    where
+        I: AsRef<[B]>,
+        Idx: AsRef<[usize]>,
+        M: AsRef<[MB]>,
+        MB: Mask,
+    {
+        let input = input.as_ref();
+        let idx = idx.as_ref();
+        let mask = mask.as_ref();
+        let len = idx.len();
+        assert_eq!(S, len, "Gathering vector from wrong number of indexes");
+        assert_eq!(S, mask.len(), "Gathering with wrong sized mask");
+        for i in 0..S {
+            unsafe {
+                if mask.get_unchecked(i).bool() {
+                    let idx = *idx.get_unchecked(i);
+                    assert!(
+                        idx < input.len(),
+                        "Gather index {} out of bounds (len {})",
+                        idx,
+                        input.len()
+                    );
+                    self[i] = *input.get_unchecked(idx);
+                }
+            }
+        }
+        self
+    }
+
+    /// Loads lanes from a slice by indexing it, with a default for out of bounds indices.
+    ///
+    /// This is similar to [`gather_load`], but instead of panicking, each lane with an index
+    /// pointing past the end of the `input` (`idx >= input.len()`) is set to `default`. The other
+    /// lanes are loaded as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let input = [1, 2, 3];
+    /// let v = u32x4::gather_load_or(&input, [2, 3, 0, usize::MAX], 42);
+    /// assert_eq!(v, u32x4::new([3, 42, 1, 42]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the `idx` slice doesn't have the same length as the vector.
+    ///
+    /// [`gather_load`]: Vector::gather_load
+    #[inline]
+    pub fn gather_load_or<I, Idx>(input: I, idx: Idx, default: B) -> Self
+    where
+        I: AsRef<[B]>,
+        Idx: AsRef<[usize]>,
+    {
+        let input = input.as_ref();
+        let idx = idx.as_ref();
+        assert_eq!(
+            S,
+            idx.len(),
+            "Gathering vector from wrong number of indexes"
+        );
+        let mut result = Self::splat(default);
+        for (lane, idx) in result.data.iter_mut().zip(idx) {
+            if let Some(value) = input.get(*idx) {
+                *lane = *value;
+            }
+        }
+        result
+    }
+
+    /// Loads the vector from a slice by indexing it with a vector of offsets.
+    ///
+    /// This is the same as [`gather_load`][Vector::gather_load], but the indices are provided as
+    /// a vector of `u32`s, the way the hardware gather instructions take them. The offsets vector
+    /// has the same number of lanes as the result, no matter the width of the base type (eg.
+    /// [`f64x4`][crate::types::f64x4] is gathered with [`u32x4`][crate::types::u32x4], while
+    /// [`u8x16`][crate::types::u8x16] needs [`u32x16`][crate::types::u32x16]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let input = (0..10).collect::<Vec<u64>>();
+    /// let offsets = u32x4::lane_ids() * 3;
+    /// let vec = u64x4::gather_vec(&input, offsets);
+    /// assert_eq!(vec, u64x4::new([0, 3, 6, 9]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If any of the offsets is out of bounds of the `input`.
+    #[inline]
+    pub fn gather_vec<I, OA>(input: I, offsets: Vector<OA, u32, S>) -> Self
+    where
+        I: AsRef<[B]>,
+        OA: Align,
+    {
+        Self::gather_load(input, offsets.cast::<OA, usize>())
+    }
+
+    /// Stores the vector without doing bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The pointed to memory must be valid for writing in `Self::LANES` consecutive cells.
+    #[inline]
+    pub unsafe fn store_unchecked(self, output: *mut B) {
+        ptr::copy_nonoverlapping(self.data.as_ptr(), output, S);
+    }
+
+    /// Stores the content into the start of a continuous slice.
+    ///
+    /// The first `Self::LANES` elements of the slice are overwritten, the rest is left intact.
+    ///
+    /// This is less general than [`scatter_store`][Vector::scatter_store], that one allows storing
+    /// to different parts of the slice.
+    ///
+    /// The counterpart of this is [`new`][Vector::new].
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = [0; 6];
+    /// u32x4::new([1, 2, 3, 4]).store(&mut data[1..]);
+    /// assert_eq!(data, [0, 1, 2, 3, 4, 0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the slice is shorter than the vector.
+    #[inline]
+    pub fn store<O: AsMut<[B]>>(self, mut output: O) {
+        let output = output.as_mut();
+        assert!(
+            output.len() >= S,
+            "Storing vector into too short slice (expected at least {}, got {})",
+            S,
+            output.len(),
+        );
+        unsafe { self.store_unchecked(output.as_mut_ptr()) }
+    }
+
+    /// Stores only the first `valid` lanes into the start of a slice.
+    ///
+    /// The `valid` count is clamped to both the number of lanes and the length of the slice, the
+    /// rest of the slice is left intact. This is useful for writing back the last partial vector,
+    /// eg. after [`vectorize_masked`][Vectorizable::vectorize_masked].
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = [0; 6];
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// v.store_partial(&mut data[4..], 4);
+    /// v.store_partial(&mut data[..], 1);
+    /// assert_eq!(data, [1, 0, 0, 0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn store_partial<O: AsMut<[B]>>(self, mut output: O, valid: usize) {
+        let output = output.as_mut();
+        let valid = valid.min(S).min(output.len());
+        output[..valid].copy_from_slice(&self.data[..valid]);
+    }
+
+    /// Views the lanes of the vector as a slice.
+    ///
+    /// This is the same as dereferencing the vector and converting the array, provided for
+    /// convenience and discoverability.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// assert_eq!(v.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[B] {
+        &self.data
+    }
+
+    /// Views the lanes of the vector as a mutable slice.
+    ///
+    /// This allows fixing up individual lanes in a scalar way.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut v = u32x4::new([1, 2, 3, 4]);
+    /// v.as_mut_slice()[1..3].copy_from_slice(&[5, 6]);
+    /// assert_eq!(v, u32x4::new([1, 5, 6, 4]));
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [B] {
+        &mut self.data
+    }
+
+    /// Splits interleaved data into one vector per field.
+    ///
+    /// The data are `N`-sized records (like `[x, y, z, x, y, z, ...]`), the result contains a
+    /// vector of the first fields, a vector of the second ones, etc. ‒ like transposing an array of
+    /// structs into a struct of arrays. Exactly `Self::LANES` records are taken.
+    ///
+    /// This is similar to [`deinterleave`][Vector::deinterleave], but works on a slice and with
+    /// any number of fields. To process longer data, consider using
+    /// [`Strided`][crate::iterators::Strided] for each of the fields.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let vertices = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let [x, y, z] = f32x2::load_deinterleaved(&vertices);
+    /// assert_eq!(x, f32x2::new([1.0, 4.0]));
+    /// assert_eq!(y, f32x2::new([2.0, 5.0]));
+    /// assert_eq!(z, f32x2::new([3.0, 6.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the length of the data is not `N * Self::LANES`.
+    #[inline]
+    pub fn load_deinterleaved<const N: usize>(data: &[B]) -> [Self; N] {
+        assert_eq!(
+            data.len(),
+            N * S,
+            "Deinterleaving wrong sized slice (expected {}, got {})",
+            N * S,
+            data.len(),
+        );
+        // All the lanes get overwritten
+        let mut result = [Self::splat(B::ONE); N];
+        for (i, val) in data.iter().enumerate() {
+            result[i % N].data[i / N] = *val;
+        }
+        result
+    }
+
+    /// Store the vector into a slice by indexing it.
+    ///
+    /// This is the inverse of [`gather_load`][Vector::gather_load]. It takes the lanes of the
+    /// vector and stores them into the slice into given indices.
+    ///
+    /// If you want to store it into a continuous slice, it is potentially faster to do it using
+    /// the `copy_from_slice` method or by [`store`][Vector::store]:
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = vec![0; 6];
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// data[0..4].copy_from_slice(&v[..]);
+    /// assert_eq!(&data[..], &[1, 2, 3, 4, 0, 0]);
+    /// v.store(&mut data[..4]);
+    /// assert_eq!(&data[..], &[1, 2, 3, 4, 0, 0]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = vec![0; 6];
+    /// let v = u32x4::new([1, 2, 3, 4]);
+    /// v.scatter_store(&mut data, [2, 5, 0, 1]);
+    /// assert_eq!(&data[..], &[3, 4, 1, 0, 0, 2]);
+    /// ```
+    ///
+    /// # Warning
+    ///
+    /// If multiple lanes are to be stored into the same slice element, it is not specified which
+    /// of them will end up being stored. It is not UB to do so and it'll always be one of them,
+    /// however it may change between versions or even between compilation targets which.
+    ///
+    /// This is to allow for potential different behaviour of different platforms.
+    ///
+    /// As this is usually a result of a bug in computing the indices, debug builds check for
+    /// duplicate indices and panic.
+    ///
+    /// # Panics
+    ///
+    /// * If the `idx` has a different length than the vector.
     /// * If any of the indices are out of bounds of `output`.
+    /// * In debug builds, if any of the indices is duplicate.
+    #[inline]
+    pub fn scatter_store<O, Idx>(self, mut output: O, idx: Idx)
+    where
+        O: AsMut<[B]>,
+        Idx: AsRef<[usize]>,
+    {
+        let output = output.as_mut();
+        let idx = idx.as_ref();
+        assert_eq!(S, idx.len(), "Scattering vector to wrong number of indexes");
+        // Check prior to starting the scatter before we write anything. Might be nicer for
+        // optimizer + we don't want to do partial scatter.
+        assert!(
+            idx.iter().all(|&l| l < output.len()),
+            "Scatter out of bounds"
+        );
+        // This is quadratic, so only in debug builds.
+        #[cfg(debug_assertions)]
+        for (i, l) in idx.iter().enumerate() {
+            assert!(!idx[..i].contains(l), "Scatter to duplicate index {}", l);
+        }
+        for i in 0..S {
+            unsafe {
+                // get_unchecked: index checked above in bulk and we use this one in hope
+                // it'll taste better to the autovectorizer and it might find a scatter
+                // insrtuction for us.
+                let idx = *idx.get_unchecked(i);
+                *output.get_unchecked_mut(idx) = self[i];
+            }
+        }
+    }
+
+    /// A masked version of [`scatter_store`].
+    ///
+    /// This acts in the same way as [`scatter_store`], except lanes disabled by the `mask` are not
+    /// stored anywhere.
+    ///
+    /// # Panics
+    ///
+    /// * If the `idx` or `mask` has a different length than the vector.
+    /// * If any of the active indices are out of bounds of `output`.
+    /// * In debug builds, if any of the active indices is duplicate.
+    ///
+    /// [`scatter_store`]: Vector::scatter_store
+    #[inline]
+    pub fn scatter_store_masked<O, Idx, M, MB>(self, mut output: O, idx: Idx, mask: M)
+    where
+        O: AsMut<[B]>,
+        Idx: AsRef<[usize]>,
+        M: AsRef<[MB]>,
+        MB: Mask,
+    {
+        let output = output.as_mut();
+        let idx = idx.as_ref();
+        let mask = mask.as_ref();
+        assert_eq!(S, idx.len(), "Scattering vector to wrong number of indexes");
+        assert_eq!(S, mask.len(), "Scattering vector with wrong sized mask");
+        // Check prior to starting the scatter before we write anything. Might be nicer for
+        // optimizer + we don't want to do partial scatter.
+        let in_bounds = idx
+            .iter()
+            .enumerate()
+            .all(|(i, &l)| !mask[i].bool() || l < output.len());
+        assert!(in_bounds, "Scatter out of bounds");
+        #[cfg(debug_assertions)]
+        for (i, l) in idx.iter().enumerate() {
+            let duplicate = || (0..i).any(|j| mask[j].bool() && idx[j] == *l);
+            assert!(
+                !mask[i].bool() || !duplicate(),
+                "Scatter to duplicate index {}",
+                l
+            );
+        }
+        for i in 0..S {
+            if mask[i].bool() {
+                unsafe {
+                    // get_unchecked: index checked above in bulk and we use this one in
+                    // hope it'll taste better to the autovectorizer and it might find a
+                    // scatter insrtuction for us.
+                    let idx = *idx.get_unchecked(i);
+                    *output.get_unchecked_mut(idx) = self[i];
+                }
+            }
+        }
+    }
+
+    /// Stores the vector into a slice by indexing it with a vector of offsets.
+    ///
+    /// This is the same as [`scatter_store`][Vector::scatter_store] (including the checks for
+    /// duplicate indices in debug builds), but the indices are provided as a vector of `u32`s.
+    /// It is the counterpart of [`gather_vec`][Vector::gather_vec].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let mut data = [0; 4];
+    /// let offsets = u32x4::lane_ids().reverse_lanes();
+    /// u64x4::new([1, 2, 3, 4]).scatter_vec(&mut data, offsets);
+    /// assert_eq!(data, [4, 3, 2, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// * If any of the offsets is out of bounds of `output`.
+    /// * In debug builds, if any of the offsets is duplicate.
+    #[inline]
+    pub fn scatter_vec<O, OA>(self, output: O, offsets: Vector<OA, u32, S>)
+    where
+        O: AsMut<[B]>,
+        OA: Align,
+    {
+        self.scatter_store(output, offsets.cast::<OA, usize>())
+    }
+
+    /// Blend self and other using mask.
+    ///
+    /// Imports enabled lanes from `other`, keeps disabled lanes from `self`. This is the
+    /// branchless way to select each lane from one of two vectors.
+    ///
+    /// The mask can be any vector or array of [mask][crate::mask] types (including `bool`) of
+    /// the same number of lanes, usually the result of some comparison. The mask types can hold
+    /// only the all-ones or all-zeros values, so there's no such thing as a partially set lane.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let odd = u32x4::new([1, 3, 5, 7]);
+    /// let even = u32x4::new([2, 4, 6, 8]);
+    /// let mask = m32x4::new([m32::TRUE, m32::FALSE, m32::TRUE, m32::FALSE]);
+    /// assert_eq!(odd.blend(even, mask), u32x4::new([2, 3, 6, 7]));
+    /// ```
+    #[inline]
+    pub fn blend<M, MB>(self, other: Self, mask: M) -> Self
+    where
+        M: AsRef<[MB]>,
+        MB: Mask,
+    {
+        let mut data = MaybeUninit::<Self>::uninit();
+        let mask = mask.as_ref();
+        unsafe {
+            for i in 0..S {
+                ptr::write(
+                    data.as_mut_ptr().cast::<B>().add(i),
+                    if mask[i].bool() { other[i] } else { self[i] },
+                );
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Checks if any of the lanes of a mask vector is set.
+    ///
+    /// This is useful to terminate vectorized search early.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// fn contains_over(data: &[u32], threshold: u32) -> bool {
+    ///     let threshold = u32x4::splat(threshold);
+    ///     data.vectorize_pad(u32x4::default())
+    ///         .any(|v: u32x4| v.gt(threshold).any())
+    /// }
+    /// assert!(contains_over(&[1, 2, 3, 4, 5, 6], 5));
+    /// assert!(!contains_over(&[1, 2, 3, 4, 5, 6], 6));
+    /// ```
+    #[inline]
+    pub fn any(self) -> bool
+    where
+        B: Mask,
+    {
+        self.data.iter().any(|l| l.bool())
+    }
+
+    /// Checks if all the lanes of a mask vector are set.
+    #[inline]
+    pub fn all(self) -> bool
+    where
+        B: Mask,
+    {
+        self.data.iter().all(|l| l.bool())
+    }
+
+    /// Checks if none of the lanes of a mask vector is set.
+    ///
+    /// This is the opposite of [`any`][Vector::any].
+    #[inline]
+    pub fn none(self) -> bool
+    where
+        B: Mask,
+    {
+        !self.any()
+    }
+
+    /// Packs a mask vector into bits of an integer.
+    ///
+    /// The lane 0 ends up in the least significant bit, lane 1 in the next one, etc. The bits
+    /// above the number of lanes are always zero. This is the operation known as `movemask` on
+    /// some platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u32x4::new([1, 5, 2, 7]);
+    /// assert_eq!(v.gt(u32x4::splat(3)).bitmask(), 0b1010);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the vector has more than 64 lanes.
+    #[inline]
+    pub fn bitmask(self) -> u64
+    where
+        B: Mask,
+    {
+        assert!(S <= 64, "Too many lanes to fit into bitmask");
+        let mut result = 0;
+        for i in 0..S {
+            result |= (self.data[i].bool() as u64) << i;
+        }
+        result
+    }
+
+    /// A lane-wise maximum.
+    ///
+    /// For floating point types, this follows the IEEE `maxNum` semantics ‒ if one of the lanes
+    /// is NaN, the other one is taken. The result is NaN only if both are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let a = u32x4::new([1, 4, 2, 5]);
+    /// let b = u32x4::new([2, 3, 2, 6]);
+    /// assert_eq!(a.maximum(b), u32x4::new([2, 4, 2, 6]));
+    ///
+    /// let c = f32x2::new([f32::NAN, 1.0]);
+    /// let d = f32x2::new([2.0, f32::NAN]);
+    /// assert_eq!(c.maximum(d), f32x2::new([2.0, 1.0]));
+    /// ```
+    #[inline]
+    pub fn maximum(self, other: Self) -> Self
+    where
+        B: PartialOrd,
+    {
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(
+                    data.as_mut_ptr().cast::<B>().add(i),
+                    max_num(self.data[i], other.data[i]),
+                );
+            }
+            data.assume_init()
+        }
+    }
+
+    /// A lane-wise minimum.
+    ///
+    /// For floating point types, this follows the IEEE `minNum` semantics ‒ if one of the lanes
+    /// is NaN, the other one is taken. The result is NaN only if both are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let a = u32x4::new([1, 4, 2, 5]);
+    /// let b = u32x4::new([2, 3, 2, 6]);
+    /// assert_eq!(a.minimum(b), u32x4::new([1, 3, 2, 5]));
+    ///
+    /// let c = f32x2::new([f32::NAN, 1.0]);
+    /// let d = f32x2::new([2.0, f32::NAN]);
+    /// assert_eq!(c.minimum(d), f32x2::new([2.0, 1.0]));
+    /// ```
+    #[inline]
+    pub fn minimum(self, other: Self) -> Self
+    where
+        B: PartialOrd,
+    {
+        let mut data = MaybeUninit::<Self>::uninit();
+        unsafe {
+            for i in 0..S {
+                ptr::write(
+                    data.as_mut_ptr().cast::<B>().add(i),
+                    min_num(self.data[i], other.data[i]),
+                );
+            }
+            data.assume_init()
+        }
+    }
+
+    /// Sums the lanes together.
+    ///
+    /// The additions are done in a tree manner: `(a[0] + a[1]) + (a[2] + a[3])`. For floating
+    /// point vectors, this is the pairwise summation, which usually has smaller rounding error
+    /// than summing the lanes one by one.
+    ///
+    /// Note that this is potentially a slow operation. Prefer to do as many operations on whole
+    /// vectors and only at the very end perform the horizontal operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1e8, -1e8, 1.0, 1.0, 1e8, -1e8, 1.0, 1.0];
+    /// // Summing one by one loses the ones added to the large intermediate value
+    /// let naive = data.iter().fold(0.0f32, |a, b| a + b);
+    /// assert_eq!(naive, 2.0);
+    /// assert_eq!(f32x8::new(data).horizontal_sum(), 4.0);
+    /// ```
+    #[inline]
+    pub fn horizontal_sum(self) -> B
+    where
+        B: Add<Output = B>,
+    {
+        self.reduce(|a, b| a + b)
+    }
+
+    /// Multiplies all the lanes of the vector.
+    ///
+    /// The multiplications are done in a tree manner: `(a[0] * a[1]) * (a[2] * a[3])`.
+    ///
+    /// Note that this is potentially a slow operation. Prefer to do as many operations on whole
+    /// vectors and only at the very end perform the horizontal operation.
+    #[inline]
+    pub fn horizontal_product(self) -> B
+    where
+        B: Mul<Output = B>,
+    {
+        self.reduce(|a, b| a * b)
+    }
+
+    /// Combines the lanes by a binary function.
+    ///
+    /// This generalizes [`horizontal_sum`][Vector::horizontal_sum] and the other horizontal
+    /// operations. The lanes are combined in the same tree manner, eg.
+    /// `f(f(a[0], a[1]), f(a[2], a[3]))`. The function is expected to be associative, otherwise
+    /// the result depends on this (unspecified) order.
+    ///
+    /// Note that this is potentially a slow operation. Prefer to do as many operations on whole
+    /// vectors and only at the very end perform the horizontal operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([1.0, 2.0, 8.0, 3.0]);
+    /// assert_eq!(v.reduce(f32::max), 8.0);
+    /// ```
     #[inline]
-    pub fn scatter_store<O, Idx>(self, mut output: O, idx: Idx)
+    pub fn reduce<F: FnMut(B, B) -> B>(self, mut f: F) -> B {
+        #[inline(always)]
+        fn inner<B: Copy, F: FnMut(B, B) -> B>(d: &[B], f: &mut F) -> B {
+            if d.len() == 1 {
+                d[0]
+            } else {
+                let mid = d.len() / 2;
+                let lo = inner(&d[..mid], f);
+                let hi = inner(&d[mid..], f);
+                f(lo, hi)
+            }
+        }
+        inner(&self.data, &mut f)
+    }
+
+    /// Computes bitwise and of all the lanes.
+    ///
+    /// This can be used to combine per-lane flags into a single value.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u8x4::new([0b1110, 0b0111, 0b1111, 0b0110]);
+    /// assert_eq!(v.horizontal_and(), 0b0110);
+    /// ```
+    #[inline]
+    pub fn horizontal_and(self) -> B
     where
-        O: AsMut<[B]>,
-        Idx: AsRef<[usize]>,
+        B: BitAnd<Output = B>,
     {
-        let output = output.as_mut();
-        let idx = idx.as_ref();
-        assert_eq!(S, idx.len(), "Scattering vector to wrong number of indexes");
-        // Check prior to starting the scatter before we write anything. Might be nicer for
-        // optimizer + we don't want to do partial scatter.
-        assert!(
-            idx.iter().all(|&l| l < output.len()),
-            "Scatter out of bounds"
+        self.reduce(|a, b| a & b)
+    }
+
+    /// Computes bitwise or of all the lanes.
+    ///
+    /// This can be used to combine per-lane flags into a single value.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u8x4::new([0b0001, 0b0100, 0, 0b0101]);
+    /// assert_eq!(v.horizontal_or(), 0b0101);
+    /// ```
+    #[inline]
+    pub fn horizontal_or(self) -> B
+    where
+        B: BitOr<Output = B>,
+    {
+        self.reduce(|a, b| a | b)
+    }
+
+    /// Computes bitwise xor of all the lanes.
+    ///
+    /// This can be used to combine per-lane flags into a single value.
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = u8x4::new([0b0001, 0b0011, 0b0111, 0]);
+    /// assert_eq!(v.horizontal_xor(), 0b0101);
+    /// ```
+    #[inline]
+    pub fn horizontal_xor(self) -> B
+    where
+        B: BitXor<Output = B>,
+    {
+        self.reduce(|a, b| a ^ b)
+    }
+
+    /// Computes the dot product of two slices.
+    ///
+    /// The slices are multiplied lane-wise in vectors of this type, accumulated into a vector and
+    /// summed by [`horizontal_sum`][Vector::horizontal_sum] at the end. The uneven end of the
+    /// slices is padded by the default value (zero).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let b = [2.0, 2.0, 2.0, 2.0, 2.0];
+    /// assert_eq!(f32x4::dot(&a, &b), 30.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the slices are of different lengths.
+    #[inline]
+    pub fn dot(a: &[B], b: &[B]) -> B
+    where
+        B: Add<Output = B> + Mul<Output = B> + Default,
+    {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "Dot product of slices of different lengths"
         );
-        for i in 0..S {
-            unsafe {
-                // get_unchecked: index checked above in bulk and we use this one in hope
-                // it'll taste better to the autovectorizer and it might find a scatter
-                // insrtuction for us.
-                let idx = *idx.get_unchecked(i);
-                *output.get_unchecked_mut(idx) = self[i];
-            }
+        let zero = Self::default();
+        let mut acc = Self::default();
+        for (a, b) in (a, b).vectorize_pad((zero, zero)) {
+            let (a, b): (Self, Self) = (a, b);
+            acc = acc + a * b;
         }
+        acc.horizontal_sum()
     }
 
-    /// A masked version of [`scatter_store`].
+    /// Sums all the elements of a slice.
     ///
-    /// This acts in the same way as [`scatter_store`], except lanes disabled by the `mask` are not
-    /// stored anywhere.
+    /// The slice is accumulated lane-wise in vectors of this type and summed by
+    /// [`horizontal_sum`][Vector::horizontal_sum] at the end. The uneven end of the slice is
+    /// padded by the default value (zero). An empty slice sums to zero.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// * If the `idx` or `mask` has a different length than the vector.
-    /// * If any of the active indices are out of bounds of `output`.
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1, 2, 3, 4, 5, 6, 7];
+    /// assert_eq!(u32x4::reduce_sum(&data), 28);
+    /// ```
+    #[inline]
+    pub fn reduce_sum(data: &[B]) -> B
+    where
+        B: Add<Output = B> + Default,
+    {
+        data.vectorize_pad(Self::default())
+            .fold(Self::default(), |acc, v: Self| acc + v)
+            .horizontal_sum()
+    }
+
+    /// Multiplies all the elements of a slice together.
     ///
-    /// [`scatter_store`]: Vector::scatter_store
+    /// Works like [`reduce_sum`][Vector::reduce_sum], but the padding is one. An empty slice
+    /// produces one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let data = [1, 2, 3, 4, 5];
+    /// assert_eq!(u32x4::reduce_product(&data), 120);
+    /// ```
     #[inline]
-    pub fn scatter_store_masked<O, Idx, M, MB>(self, mut output: O, idx: Idx, mask: M)
+    pub fn reduce_product(data: &[B]) -> B
     where
-        O: AsMut<[B]>,
-        Idx: AsRef<[usize]>,
-        M: AsRef<[MB]>,
-        MB: Mask,
+        B: Mul<Output = B>,
     {
-        let output = output.as_mut();
-        let idx = idx.as_ref();
-        let mask = mask.as_ref();
-        assert_eq!(S, idx.len(), "Scattering vector to wrong number of indexes");
-        assert_eq!(S, mask.len(), "Scattering vector with wrong sized mask");
-        // Check prior to starting the scatter before we write anything. Might be nicer for
-        // optimizer + we don't want to do partial scatter.
-        let in_bounds = idx
-            .iter()
-            .enumerate()
-            .all(|(i, &l)| !mask[i].bool() || l < output.len());
-        assert!(in_bounds, "Scatter out of bounds");
-        for i in 0..S {
-            if mask[i].bool() {
-                unsafe {
-                    // get_unchecked: index checked above in bulk and we use this one in
-                    // hope it'll taste better to the autovectorizer and it might find a
-                    // scatter insrtuction for us.
-                    let idx = *idx.get_unchecked(i);
-                    *output.get_unchecked_mut(idx) = self[i];
-                }
-            }
-        }
+        let one = Self::splat(B::ONE);
+        data.vectorize_pad(one)
+            .fold(one, |acc, v: Self| acc * v)
+            .horizontal_product()
     }
 
-    /// Blend self and other using mask.
+    /// Finds the maximum of a slice.
     ///
-    /// Imports enabled lanes from `other`, keeps disabled lanes from `self`.
+    /// The slice is processed lane-wise in vectors of this type, with the uneven end padded by
+    /// its first element. NaNs are ignored, like in [`maximum`][Vector::maximum]. Returns `None`
+    /// for an empty slice.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let odd = u32x4::new([1, 3, 5, 7]);
-    /// let even = u32x4::new([2, 4, 6, 8]);
-    /// let mask = m32x4::new([m32::TRUE, m32::FALSE, m32::TRUE, m32::FALSE]);
-    /// assert_eq!(odd.blend(even, mask), u32x4::new([2, 3, 6, 7]));
+    /// let data = [3.0, -1.0, 8.0, 2.0, 5.0];
+    /// assert_eq!(f32x4::reduce_max(&data), Some(8.0));
+    /// assert_eq!(f32x4::reduce_max(&[]), None);
     /// ```
     #[inline]
-    pub fn blend<M, MB>(self, other: Self, mask: M) -> Self
+    pub fn reduce_max(data: &[B]) -> Option<B>
     where
-        M: AsRef<[MB]>,
-        MB: Mask,
+        B: PartialOrd,
     {
-        let mut data = MaybeUninit::<Self>::uninit();
-        let mask = mask.as_ref();
-        unsafe {
-            for i in 0..S {
-                ptr::write(
-                    data.as_mut_ptr().cast::<B>().add(i),
-                    if mask[i].bool() { other[i] } else { self[i] },
-                );
-            }
-            data.assume_init()
-        }
+        let pad = Self::splat(*data.first()?);
+        let max = data
+            .vectorize_pad(pad)
+            .fold(pad, |acc, v: Self| acc.maximum(v));
+        Some(max.horizontal_max())
     }
 
-    /// A lane-wise maximum.
+    /// Finds the minimum of a slice.
+    ///
+    /// Works like [`reduce_max`][Vector::reduce_max].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let a = u32x4::new([1, 4, 2, 5]);
-    /// let b = u32x4::new([2, 3, 2, 6]);
-    /// assert_eq!(a.maximum(b), u32x4::new([2, 4, 2, 6]));
+    /// let data = [3, -1, 8, 2, 5];
+    /// assert_eq!(i32x4::reduce_min(&data), Some(-1));
     /// ```
     #[inline]
-    pub fn maximum(self, other: Self) -> Self
+    pub fn reduce_min(data: &[B]) -> Option<B>
     where
         B: PartialOrd,
     {
-        let m = self.lt(other);
-        self.blend(other, m)
+        let pad = Self::splat(*data.first()?);
+        let min = data
+            .vectorize_pad(pad)
+            .fold(pad, |acc, v: Self| acc.minimum(v));
+        Some(min.horizontal_min())
     }
 
-    /// A lane-wise maximum.
+    /// Lane-wise restriction of the values into the interval between `lo` and `hi`.
+    ///
+    /// This is equivalent to `self.maximum(lo).minimum(hi)`. Unlike the scalar `clamp`, this
+    /// doesn't panic if `lo > hi` in some lane; the `hi` is returned in such case. Due to the
+    /// NaN semantics of [`maximum`][Vector::maximum], NaN lanes of `self` are replaced by `lo`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use slipstream::prelude::*;
-    /// let a = u32x4::new([1, 4, 2, 5]);
-    /// let b = u32x4::new([2, 3, 2, 6]);
-    /// assert_eq!(a.minimum(b), u32x4::new([1, 3, 2, 5]));
+    /// let v = i32x4::new([-20, 0, 100, 300]);
+    /// let clamped = v.clamp(i32x4::splat(0), i32x4::splat(255));
+    /// assert_eq!(clamped, i32x4::new([0, 0, 100, 255]));
     /// ```
     #[inline]
-    pub fn minimum(self, other: Self) -> Self
+    pub fn clamp(self, lo: Self, hi: Self) -> Self
     where
         B: PartialOrd,
     {
-        let m = self.gt(other);
-        self.blend(other, m)
+        self.maximum(lo).minimum(hi)
     }
 
-    // TODO: Example
-    /// Sums the lanes together.
+    /// Finds the maximum of all the lanes.
     ///
-    /// The additions are done in a tree manner: `(a[0] + a[1]) + (a[2] + a[3])`.
+    /// For floating point types, NaN lanes are ignored (see [`maximum`][Vector::maximum]). The
+    /// result is NaN only if all the lanes are NaN.
     ///
     /// Note that this is potentially a slow operation. Prefer to do as many operations on whole
     /// vectors and only at the very end perform the horizontal operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([1.0, f32::NAN, 3.0, -2.0]);
+    /// assert_eq!(v.horizontal_max(), 3.0);
+    /// ```
     #[inline]
-    pub fn horizontal_sum(self) -> B
+    pub fn horizontal_max(self) -> B
     where
-        B: Add<Output = B>,
+        B: PartialOrd,
     {
         #[inline(always)]
-        fn inner<B: Copy + Add<Output = B>>(d: &[B]) -> B {
+        fn inner<B: Copy + PartialOrd>(d: &[B]) -> B {
             if d.len() == 1 {
                 d[0]
             } else {
                 let mid = d.len() / 2;
-                inner(&d[..mid]) + inner(&d[mid..])
+                max_num(inner(&d[..mid]), inner(&d[mid..]))
             }
         }
         inner(&self.data)
     }
 
-    /// Multiplies all the lanes of the vector.
+    /// Finds the minimum of all the lanes.
     ///
-    /// The multiplications are done in a tree manner: `(a[0] * a[1]) * (a[2] * a[3])`.
+    /// For floating point types, NaN lanes are ignored (see [`minimum`][Vector::minimum]). The
+    /// result is NaN only if all the lanes are NaN.
     ///
     /// Note that this is potentially a slow operation. Prefer to do as many operations on whole
     /// vectors and only at the very end perform the horizontal operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([1.0, f32::NAN, 3.0, -2.0]);
+    /// assert_eq!(v.horizontal_min(), -2.0);
+    /// ```
     #[inline]
-    pub fn horizontal_product(self) -> B
+    pub fn horizontal_min(self) -> B
     where
-        B: Mul<Output = B>,
+        B: PartialOrd,
     {
         #[inline(always)]
-        fn inner<B: Copy + Mul<Output = B>>(d: &[B]) -> B {
+        fn inner<B: Copy + PartialOrd>(d: &[B]) -> B {
             if d.len() == 1 {
                 d[0]
             } else {
                 let mid = d.len() / 2;
-                inner(&d[..mid]) * inner(&d[mid..])
+                min_num(inner(&d[..mid]), inner(&d[mid..]))
             }
         }
         inner(&self.data)
@@ -686,6 +1890,9 @@ where
         /// Lane-wise `==`.
         PartialEq => eq;
 
+        /// Lane-wise `!=`.
+        PartialEq => ne;
+
         /// Lane-wise `<`.
         PartialOrd => lt;
 
@@ -717,6 +1924,150 @@ impl<A: Align, B: Debug + Repr, const S: usize> Debug for Vector<A, B, S> {
     }
 }
 
+macro_rules! fmt_impl {
+    ($($f: ident),*) => {
+        $(
+            /// Formats the vector like `u32x4(1, 2, 3, 4)`.
+            ///
+            /// The formatting options (like width) are applied to each lane separately.
+            impl<A: Align, B: $f + Repr, const S: usize> $f for Vector<A, B, S> {
+                fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+                    write!(fmt, "{}x{}(", B::NAME, S)?;
+                    for (i, lane) in self.data.iter().enumerate() {
+                        if i > 0 {
+                            fmt.write_str(", ")?;
+                        }
+                        $f::fmt(lane, fmt)?;
+                    }
+                    fmt.write_str(")")
+                }
+            }
+        )*
+    };
+}
+
+fmt_impl!(Display, LowerHex, UpperHex);
+
+/// Creates the vector from an array of the correct size.
+///
+/// Unlike [`new`][Vector::new], this checks the size at compile time.
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// let v: u32x4 = [1, 2, 3, 4].into();
+/// assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use slipstream::prelude::*;
+/// let v: u32x4 = [1, 2, 3].into();
+/// ```
+impl<A: Align, B: Repr, const S: usize> From<[B; S]> for Vector<A, B, S> {
+    #[inline]
+    fn from(data: [B; S]) -> Self {
+        Self::from_array(data)
+    }
+}
+
+impl<A: Align, B: Repr, const S: usize> From<Vector<A, B, S>> for [B; S] {
+    #[inline]
+    fn from(vector: Vector<A, B, S>) -> [B; S] {
+        vector.data
+    }
+}
+
+/// An error when creating a vector from a slice of the wrong length.
+///
+/// Returned by the [`TryFrom`] conversion from a slice into a [`Vector`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LengthError {
+    /// The number of lanes of the vector.
+    pub expected: usize,
+    /// The length of the provided slice.
+    pub actual: usize,
+}
+
+impl Display for LengthError {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(
+            fmt,
+            "Creating vector from the wrong sized slice (expected {}, got {})",
+            self.expected, self.actual,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+/// Creates the vector from a slice, checking its length.
+///
+/// This is the non-panicking variant of [`new`][Vector::new].
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// # use slipstream::vector::LengthError;
+/// use std::convert::TryFrom;
+///
+/// let v = u32x4::try_from(&[1, 2, 3, 4][..]).unwrap();
+/// assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+///
+/// let err = u32x4::try_from(&[1, 2, 3][..]).unwrap_err();
+/// assert_eq!(err, LengthError { expected: 4, actual: 3 });
+/// ```
+impl<A: Align, B: Repr, const S: usize> TryFrom<&[B]> for Vector<A, B, S> {
+    type Error = LengthError;
+    #[inline]
+    fn try_from(input: &[B]) -> Result<Self, LengthError> {
+        if input.len() == S {
+            Ok(unsafe { Self::new_unchecked(input.as_ptr()) })
+        } else {
+            Err(LengthError {
+                expected: S,
+                actual: input.len(),
+            })
+        }
+    }
+}
+
+/// Iterates over the lanes of the vector.
+///
+/// ```rust
+/// # use slipstream::prelude::*;
+/// let v = u32x4::new([1, 2, 3, 4]);
+/// let mut sum = 0;
+/// for lane in v {
+///     sum += lane;
+/// }
+/// assert_eq!(sum, v.horizontal_sum());
+/// ```
+impl<A: Align, B: Repr, const S: usize> IntoIterator for Vector<A, B, S> {
+    type Item = B;
+    type IntoIter = core::array::IntoIter<B, S>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.data)
+    }
+}
+
+impl<'a, A: Align, B: Repr, const S: usize> IntoIterator for &'a Vector<A, B, S> {
+    type Item = &'a B;
+    type IntoIter = core::slice::Iter<'a, B>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, A: Align, B: Repr, const S: usize> IntoIterator for &'a mut Vector<A, B, S> {
+    type Item = &'a mut B;
+    type IntoIter = core::slice::IterMut<'a, B>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 impl<A: Align, B: Repr, const S: usize> Deref for Vector<A, B, S> {
     type Target = [B; S];
     #[inline]
@@ -826,8 +2177,48 @@ bin_op_impl!(BitXor, bitxor, BitXorAssign, bitxor_assign);
 bin_op_impl!(Shl, shl, ShlAssign, shl_assign);
 bin_op_impl!(Shr, shr, ShrAssign, shr_assign);
 
-una_op_impl!(Neg, neg);
-una_op_impl!(Not, not);
+una_op_impl!(Neg, neg);
+una_op_impl!(Not, not);
+
+/// Operators with the scalar on the left side, like `2 * v`.
+///
+/// These can't be implemented generically because of the orphan rules, so it is done for each
+/// concrete base type.
+macro_rules! scalar_op_impl {
+    ($($t: ty),*) => {
+        $(
+            scalar_op_impl!(@op $t, Add, add);
+            scalar_op_impl!(@op $t, Sub, sub);
+            scalar_op_impl!(@op $t, Mul, mul);
+            scalar_op_impl!(@op $t, Div, div);
+        )*
+    };
+    (@op $t: ty, $tr: ident, $meth: ident) => {
+        impl<A: Align, const S: usize> $tr<Vector<A, $t, S>> for $t {
+            type Output = Vector<A, $t, S>;
+            #[inline]
+            fn $meth(self, rhs: Vector<A, $t, S>) -> Vector<A, $t, S> {
+                $tr::$meth(Vector::splat(self), rhs)
+            }
+        }
+    };
+}
+
+scalar_op_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+scalar_op_impl!(
+    core::num::Wrapping<u8>,
+    core::num::Wrapping<u16>,
+    core::num::Wrapping<u32>,
+    core::num::Wrapping<u64>,
+    core::num::Wrapping<u128>,
+    core::num::Wrapping<usize>,
+    core::num::Wrapping<i8>,
+    core::num::Wrapping<i16>,
+    core::num::Wrapping<i32>,
+    core::num::Wrapping<i64>,
+    core::num::Wrapping<i128>,
+    core::num::Wrapping<isize>
+);
 
 impl<A: Align, B: PartialEq + Repr, const S: usize> PartialEq for Vector<A, B, S> {
     #[inline]
@@ -838,6 +2229,29 @@ impl<A: Align, B: PartialEq + Repr, const S: usize> PartialEq for Vector<A, B, S
 
 impl<A: Align, B: Eq + Repr, const S: usize> Eq for Vector<A, B, S> {}
 
+// Lexicographic, the same as arrays.
+impl<A: Align, B: PartialOrd + Repr, const S: usize> PartialOrd for Vector<A, B, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<A: Align, B: Ord + Repr, const S: usize> Ord for Vector<A, B, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
+// Hashing the array is consistent with the PartialEq above.
+impl<A: Align, B: Hash + Repr, const S: usize> Hash for Vector<A, B, S> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state)
+    }
+}
+
 impl<A: Align, B: PartialEq + Repr, const S: usize> PartialEq<[B; S]> for Vector<A, B, S> {
     #[inline]
     fn eq(&self, other: &[B; S]) -> bool {
@@ -854,6 +2268,9 @@ impl<A: Align, B: PartialEq + Repr, const S: usize> PartialEq<Vector<A, B, S>> f
 
 #[cfg(test)]
 mod tests {
+    use core::num::Wrapping;
+
+    use super::align::Align1;
     use super::*;
     use crate::prelude::*;
 
@@ -865,6 +2282,248 @@ mod tests {
         V::new([1, 2, 3]);
     }
 
+    #[test]
+    fn from_array() {
+        let v: u32x4 = [1, 2, 3, 4].into();
+        assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+        assert_eq!(f32x2::from([1.0, 2.0]), f32x2::new([1.0, 2.0]));
+
+        let a: [u32; 4] = v.into();
+        assert_eq!(a, [1, 2, 3, 4]);
+        assert_eq!(<[u16; 4]>::from(V::splat(2)), [2; 4]);
+    }
+
+    #[test]
+    fn lane_iter() {
+        let v = f32x4::new([1.0, 2.0, 3.0, 4.5]);
+        let it = v.into_iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.sum::<f32>(), v.horizontal_sum());
+        assert_eq!(
+            v.into_iter().rev().collect::<Vec<_>>(),
+            [4.5, 3.0, 2.0, 1.0]
+        );
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), v.to_vec());
+
+        let mut v = u32x4::new([1, 2, 3, 4]);
+        for l in &mut v {
+            *l *= 2;
+        }
+        assert_eq!((&v).into_iter().copied().collect::<Vec<_>>(), [2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn extract_replace() {
+        let v = V::new([1, 2, 3, 4]);
+        assert_eq!(v.extract(0), 1);
+        assert_eq!(v.extract(3), 4);
+        assert_eq!(v.replace(3, 10), V::new([1, 2, 3, 10]));
+        unsafe {
+            assert_eq!(v.extract_unchecked(1), 2);
+            assert_eq!(v.replace_unchecked(0, 7), V::new([7, 2, 3, 4]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Lane index 4 out of bounds (4 lanes)")]
+    fn extract_oob() {
+        V::splat(1).extract(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lane index 5 out of bounds (4 lanes)")]
+    fn replace_oob() {
+        V::splat(1).replace(5, 2);
+    }
+
+    #[test]
+    fn from_fn() {
+        assert_eq!(u32x4::from_fn(|i| i as u32), u32x4::new([0, 1, 2, 3]));
+
+        let mut calls = Vec::new();
+        let v = f32x8::from_fn(|i| {
+            calls.push(i);
+            i as f32 * 0.5
+        });
+        assert_eq!(calls, (0..8).collect::<Vec<_>>());
+        assert_eq!(v[7], 3.5);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(u32x4::try_from(&data[..4]), Ok(u32x4::new([1, 2, 3, 4])));
+        assert_eq!(
+            u32x4::try_from(&data[..3]),
+            Err(LengthError {
+                expected: 4,
+                actual: 3,
+            }),
+        );
+        assert_eq!(
+            u32x4::try_from(&data[..]),
+            Err(LengthError {
+                expected: 4,
+                actual: 5,
+            }),
+        );
+        assert_eq!(
+            LengthError {
+                expected: 4,
+                actual: 5,
+            }
+            .to_string(),
+            "Creating vector from the wrong sized slice (expected 4, got 5)",
+        );
+    }
+
+    #[test]
+    fn const_ctor() {
+        const ONES: u32x4 = u32x4::splat(1);
+        const COEFFS: f32x4 = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+        static MASKS: [m8x4; 2] = [m8x4::splat(m8::TRUE), m8x4::splat(m8::FALSE)];
+
+        assert_eq!(u32x4::new([1, 2, 3, 4]) + ONES, u32x4::new([2, 3, 4, 5]));
+        assert_eq!((COEFFS * f32x4::splat(2.0)).horizontal_sum(), 20.0);
+        assert_eq!(MASKS[0].bitmask(), 0b1111);
+        assert_eq!(MASKS[1].bitmask(), 0);
+    }
+
+    #[test]
+    fn index() {
+        let mut v = V::new([1, 2, 3, 4]);
+        assert_eq!(v[2], 3);
+        v[2] = 10;
+        assert_eq!(v, V::new([1, 2, 10, 4]));
+        assert_eq!(v[1..3], [2, 10]);
+
+        let mut data = [0u16; 6];
+        for mut p in (&mut data[..]).vectorize_pad(V::default()) {
+            p[0] = 5;
+        }
+        assert_eq!(data, [5, 0, 0, 0, 5, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_oob() {
+        let mut v = V::splat(1);
+        v[4] = 2;
+    }
+
+    #[test]
+    fn broadcast_lane() {
+        let v = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.broadcast_lane(2), f32x4::splat(3.0));
+        assert_eq!(v.broadcast_lane(0), f32x4::splat(1.0));
+        assert_eq!(V::new([1, 2, 3, 4]).broadcast_lane(3), V::splat(4));
+    }
+
+    #[test]
+    fn map_lanes() {
+        let v = i32x8::new([1, -2, 3, -4, 5, -6, 7, 100]);
+        assert_eq!(v.map_lanes(|l| l * l), v * v);
+
+        let mut seen = Vec::new();
+        let f = f32x4::new([1.0, 2.0, 3.0, 4.0]).map_lanes(|l| {
+            seen.push(l);
+            -l
+        });
+        assert_eq!(f, -f32x4::new([1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(seen, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn zip_lanes() {
+        let a = i32x8::new([1, -2, 3, -4, 5, -6, 7, 100]);
+        let b = i32x8::new([0, 0, 4, -5, 5, 6, -7, 99]);
+        assert_eq!(a.zip_lanes(b, i32::min), a.minimum(b));
+        assert_eq!(a.zip_lanes(b, |a, b| a - b), a - b);
+
+        let f = f32x4::new([1.0, f32::NAN, -0.5, 2.0]);
+        let g = f32x4::new([0.5, 3.0, f32::NAN, 2.5]);
+        assert_eq!(f.zip_lanes(g, f32::min), f.minimum(g));
+    }
+
+    #[test]
+    fn first_last() {
+        let v = i16x8::new([-1, 2, 3, 4, 5, 6, 7, -8]);
+        assert_eq!(v.first_lane(), -1);
+        assert_eq!(v.last_lane(), -8);
+        assert_eq!(v.reverse_lanes().first_lane(), v.last_lane());
+        let single = Vector::<Align1, i16, 1>::splat(5);
+        assert_eq!((single.first_lane(), single.last_lane()), (5, 5));
+        // The slice methods are still reachable
+        assert_eq!(v.first(), Some(&-1));
+        assert_eq!(v.last(), Some(&-8));
+    }
+
+    #[test]
+    #[should_panic(expected = "Lane index 4 out of bounds")]
+    fn broadcast_lane_oob() {
+        f32x4::splat(1.0).broadcast_lane(4);
+    }
+
+    #[test]
+    fn rotate_lanes() {
+        let v = V::new([1, 2, 3, 4]);
+        assert_eq!(v.rotate_lanes_left(0), v);
+        assert_eq!(v.rotate_lanes_right(0), v);
+        assert_eq!(v.rotate_lanes_left(4), v);
+        assert_eq!(v.rotate_lanes_right(4), v);
+        assert_eq!(v.rotate_lanes_left(2), V::new([3, 4, 1, 2]));
+        assert_eq!(v.rotate_lanes_right(3), V::new([2, 3, 4, 1]));
+        assert_eq!(v.rotate_lanes_left(7), V::new([4, 1, 2, 3]));
+        assert_eq!(v.rotate_lanes_left(3).rotate_lanes_right(3), v);
+    }
+
+    #[test]
+    fn reverse_lanes() {
+        let v = i16x8::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let r = v.reverse_lanes();
+        assert_eq!(r, i16x8::new([8, 7, 6, 5, 4, 3, 2, 1]));
+        assert_eq!(r.reverse_lanes(), v);
+    }
+
+    struct Broadcast0;
+
+    impl Swizzle<4> for Broadcast0 {
+        const INDICES: [usize; 4] = [0; 4];
+    }
+
+    struct Interleave;
+
+    impl Swizzle2<4> for Interleave {
+        const INDICES: [usize; 4] = [0, 4, 1, 5];
+    }
+
+    #[test]
+    fn swizzle() {
+        let v = V::new([1, 2, 3, 4]);
+        assert_eq!(v.swizzle::<Broadcast0>(), V::splat(1));
+        let w = V::new([5, 6, 7, 8]);
+        assert_eq!(v.shuffle2::<Interleave>(w), V::new([1, 5, 2, 6]));
+        assert_eq!(w.shuffle2::<Interleave>(v), V::new([5, 1, 6, 2]));
+    }
+
+    #[test]
+    fn interleave() {
+        let a = V::new([1, 2, 3, 4]);
+        let b = V::new([5, 6, 7, 8]);
+        let (lo, hi) = a.interleave(b);
+        assert_eq!(lo, V::new([1, 5, 2, 6]));
+        assert_eq!(hi, V::new([3, 7, 4, 8]));
+        assert_eq!(lo.deinterleave(hi), (a, b));
+
+        // Odd number of lanes
+        let a = Vector::<Align1, u8, 3>::new([1, 2, 3]);
+        let b = Vector::<Align1, u8, 3>::new([4, 5, 6]);
+        let (lo, hi) = a.interleave(b);
+        assert_eq!(lo, [1, 4, 2]);
+        assert_eq!(hi, [5, 3, 6]);
+        assert_eq!(lo.deinterleave(hi), (a, b));
+    }
+
     #[test]
     fn shuffle() {
         let v1 = V::new([1, 2, 3, 4]);
@@ -881,6 +2540,76 @@ mod tests {
         assert_eq!(v, [1, 3, 5, 7]);
     }
 
+    #[test]
+    fn store() {
+        let mut data = [0; 5];
+        V::new([1, 2, 3, 4]).store(&mut data[..4]);
+        assert_eq!(data, [1, 2, 3, 4, 0]);
+        V::new([5, 6, 7, 8]).store(&mut data[1..]);
+        assert_eq!(data, [1, 5, 6, 7, 8]);
+        V::splat(9).store(&mut data);
+        assert_eq!(data, [9, 9, 9, 9, 8]);
+        unsafe { V::splat(3).store_unchecked(data[1..].as_mut_ptr()) };
+        assert_eq!(data, [9, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn store_partial() {
+        let mut data = [0u32; 3];
+        u32x4::new([1, 2, 3, 4]).store_partial(&mut data, 3);
+        assert_eq!(data, [1, 2, 3]);
+        u32x4::splat(5).store_partial(&mut data, 1);
+        assert_eq!(data, [5, 2, 3]);
+        u32x4::splat(6).store_partial(&mut data, 10);
+        assert_eq!(data, [6, 6, 6]);
+        u32x4::splat(7).store_partial(&mut data, 0);
+        assert_eq!(data, [6, 6, 6]);
+
+        let mut data = [0u32; 6];
+        u32x4::new([1, 2, 3, 4]).store_partial(&mut data, 10);
+        assert_eq!(data, [1, 2, 3, 4, 0, 0]);
+    }
+
+    #[test]
+    fn load_deinterleaved() {
+        let data = (0..12).map(|i| i as f32).collect::<Vec<_>>();
+        let [x, y, z] = f32x4::load_deinterleaved(&data);
+        assert_eq!(x, f32x4::new([0.0, 3.0, 6.0, 9.0]));
+        assert_eq!(y, f32x4::new([1.0, 4.0, 7.0, 10.0]));
+        assert_eq!(z, f32x4::new([2.0, 5.0, 8.0, 11.0]));
+
+        let [a, b] = u16x4::load_deinterleaved(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!((a, b), (u16x4::new([1, 3, 5, 7]), u16x4::new([2, 4, 6, 8])));
+        let [a] = u16x4::load_deinterleaved(&[1, 2, 3, 4]);
+        assert_eq!(a, u16x4::new([1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Deinterleaving wrong sized slice")]
+    fn load_deinterleaved_wrong_len() {
+        u32x4::load_deinterleaved::<3>(&[0; 11]);
+    }
+
+    #[test]
+    fn slice_view() {
+        let mut v = V::new([1, 2, 3, 4]);
+        let slice = v.as_slice();
+        assert_eq!(slice.len(), V::LANES);
+        assert_eq!(slice.iter().sum::<u16>(), 10);
+        assert_eq!(slice.iter().position(|l| *l == 3), Some(2));
+
+        let slice = v.as_mut_slice();
+        slice[0] = 10;
+        slice.swap(1, 3);
+        assert_eq!(v, V::new([10, 4, 3, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Storing vector into too short slice")]
+    fn store_short() {
+        V::splat(1).store(&mut [0; 3]);
+    }
+
     #[test]
     fn scatter() {
         let v = V::new([1, 2, 3, 4]);
@@ -890,11 +2619,26 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Gather out of bounds")]
+    fn gather_last() {
+        let v = V::gather_load([1, 2, 3], [2, 1, 0, 2]);
+        assert_eq!(v, [3, 2, 1, 3]);
+        // Disabled lanes are not checked
+        let v = V::splat(0).gather_load_masked([1, 2, 3], [2, 42, 0, 2], [true, false, true, true]);
+        assert_eq!(v, [3, 0, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gather index 3 out of bounds (len 3)")]
     fn gather_oob() {
         V::gather_load([1, 2, 3], [0, 1, 2, 3]);
     }
 
+    #[test]
+    #[should_panic(expected = "Gather index 42 out of bounds (len 40)")]
+    fn gather_masked_oob() {
+        V::splat(0).gather_load_masked([0; 40], [0, 42, 1, 2], [false, true, true, true]);
+    }
+
     #[test]
     #[should_panic(expected = "Gathering vector from wrong number of indexes")]
     fn gather_idx_cnt() {
@@ -918,6 +2662,223 @@ mod tests {
     // TODO: Tests for out of bounds index on masked loads/stores + tests for index out of bound
     // but disabled by the mask
 
+    #[test]
+    fn arith() {
+        let a = f32x4::new([1.0, 2.5, -3.0, 4.0]);
+        let b = f32x4::new([2.0, 4.0, 0.5, -0.25]);
+
+        let mul = a * b;
+        let sub = a - b;
+        let div = a / b;
+        for i in 0..4 {
+            assert_eq!(mul[i], a[i] * b[i]);
+            assert_eq!(sub[i], a[i] - b[i]);
+            assert_eq!(div[i], a[i] / b[i]);
+        }
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, mul);
+        c -= 1.0;
+        assert_eq!(c, mul - f32x4::splat(1.0));
+    }
+
+    #[test]
+    fn neg() {
+        let v = i32x4::new([1, -2, 3, -4]);
+        assert_eq!(-v, i32x4::new([-1, 2, -3, 4]));
+        assert_eq!(-(-v), v);
+
+        let f = -f64x2::new([0.0, -1.5]);
+        assert_eq!(f, f64x2::new([-0.0, 1.5]));
+        assert!(f[0].is_sign_negative());
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let v = u32x4::new([1, 2, 3, 4]);
+        assert_eq!(v * 2, u32x4::new([2, 4, 6, 8]));
+        assert_eq!(2 * v, u32x4::new([2, 4, 6, 8]));
+        assert_eq!(v + 1, u32x4::new([2, 3, 4, 5]));
+        assert_eq!(1 + v, u32x4::new([2, 3, 4, 5]));
+        assert_eq!(v - 1, u32x4::new([0, 1, 2, 3]));
+        assert_eq!(10 - v, u32x4::new([9, 8, 7, 6]));
+        assert_eq!(v / 2, u32x4::new([0, 1, 1, 2]));
+        assert_eq!(12 / v, u32x4::new([12, 6, 4, 3]));
+
+        let f = f32x2::new([2.0, 4.0]);
+        assert_eq!(1.0 / f, f32x2::new([0.5, 0.25]));
+        let w = wu8x2::new([Wrapping(200), Wrapping(0)]);
+        assert_eq!(Wrapping(100) + w, wu8x2::new([Wrapping(44), Wrapping(100)]));
+    }
+
+    #[test]
+    fn sum_product() {
+        let vs = [
+            u32x4::new([1, 2, 3, 4]),
+            u32x4::new([5, 6, 7, 8]),
+            u32x4::new([9, 10, 11, 12]),
+        ];
+        assert_eq!(vs.iter().copied().sum::<u32x4>(), vs[0] + vs[1] + vs[2]);
+        assert_eq!(vs.iter().copied().product::<u32x4>(), vs[0] * vs[1] * vs[2]);
+        assert_eq!(core::iter::empty().sum::<u32x4>(), u32x4::splat(0));
+        assert_eq!(core::iter::empty().product::<u32x4>(), u32x4::splat(1));
+
+        let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let sum = data.vectorize().sum::<f32x4>();
+        assert_eq!(sum, f32x4::new([6.0, 8.0, 10.0, 12.0]));
+    }
+
+    #[test]
+    fn reduce() {
+        let data = (1..=11).collect::<Vec<u32>>();
+        assert_eq!(u32x4::reduce_sum(&data), 66);
+        assert_eq!(u32x4::reduce_product(&data), 39_916_800);
+        assert_eq!(u32x4::reduce_max(&data), Some(11));
+        assert_eq!(u32x4::reduce_min(&data), Some(1));
+
+        let data = [2.0, -7.5, f32::NAN, 4.0, 0.5];
+        assert_eq!(f32x4::reduce_sum(&data[3..]), 4.5);
+        assert_eq!(f32x4::reduce_product(&data[..2]), -15.0);
+        assert_eq!(f32x4::reduce_max(&data), Some(4.0));
+        assert_eq!(f32x4::reduce_min(&data), Some(-7.5));
+
+        let empty: [i16; 0] = [];
+        assert_eq!(i16x8::reduce_sum(&empty), 0);
+        assert_eq!(i16x8::reduce_product(&empty), 1);
+        assert_eq!(i16x8::reduce_max(&empty), None);
+        assert_eq!(i16x8::reduce_min(&empty), None);
+    }
+
+    #[test]
+    fn rem() {
+        let v = u32x4::new([10, 11, 12, 13]);
+        assert_eq!(v % u32x4::new([3, 4, 5, 13]), u32x4::new([1, 3, 2, 0]));
+        assert_eq!(v % 4, u32x4::new([2, 3, 0, 1]));
+
+        let mut w = i32x4::new([-7, 7, -8, 8]);
+        w %= 3;
+        assert_eq!(w, i32x4::new([-1, 1, -2, 2]));
+        w %= i32x4::new([1, 2, 3, 4]);
+        assert_eq!(w, i32x4::new([0, 1, -2, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn rem_by_zero() {
+        let _ = u32x4::splat(5) % u32x4::new([1, 2, 0, 4]);
+    }
+
+    #[test]
+    fn bitwise() {
+        let data = u32x4::new([0x1234, 0xffff_0000, 0xf0f0, 7]);
+        let mask = u32x4::new([0xff, 0xff00_0000, 0, u32::MAX]);
+
+        assert_eq!(data & mask, u32x4::new([0x34, 0xff00_0000, 0, 7]));
+        assert_eq!(
+            data | mask,
+            u32x4::new([0x12ff, 0xffff_0000, 0xf0f0, u32::MAX])
+        );
+        assert_eq!(data ^ data, u32x4::default());
+        assert_eq!(!mask, u32x4::new([!0xff, 0x00ff_ffff, u32::MAX, 0]));
+
+        let mut d = i8x4::new([1, 2, 3, -1]);
+        d &= i8x4::splat(1);
+        assert_eq!(d, i8x4::new([1, 0, 1, 1]));
+        d |= 2;
+        assert_eq!(d, i8x4::new([3, 2, 3, 3]));
+        d ^= i8x4::new([3, 0, 0, 3]);
+        assert_eq!(d, i8x4::new([0, 2, 3, 0]));
+    }
+
+    #[test]
+    fn shifts() {
+        let v = u32x4::new([1, 2, 0x8000_0000, u32::MAX]);
+        assert_eq!(v << 3, u32x4::new([8, 16, 0, u32::MAX - 7]));
+        assert_eq!(v >> 1, u32x4::new([0, 1, 0x4000_0000, u32::MAX >> 1]));
+        assert_eq!(
+            v << u32x4::new([0, 1, 2, 31]),
+            u32x4::new([1, 4, 0, 0x8000_0000])
+        );
+        assert_eq!(v >> u32x4::new([0, 1, 31, 31]), u32x4::new([1, 1, 1, 1]));
+
+        // Arithmetic shift for the signed ones
+        let s = i32x4::new([-8, 8, i32::MIN, -1]);
+        assert_eq!(s >> 2, i32x4::new([-2, 2, i32::MIN / 4, -1]));
+        assert_eq!(s >> 31, i32x4::new([-1, 0, -1, -1]));
+
+        let mut a = u8x4::splat(1);
+        a <<= 7;
+        assert_eq!(a, u8x4::splat(128));
+        a >>= u8x4::new([0, 1, 6, 7]);
+        assert_eq!(a, u8x4::new([128, 64, 2, 1]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn shift_overflow() {
+        let _ = u32x4::splat(1) << 32;
+    }
+
+    #[test]
+    fn minmax_scalar() {
+        let a = u16x8::new([1, 8, 3, 0, u16::MAX, 5, 5, 100]);
+        let b = u16x8::new([2, 7, 3, 1, 0, 4, 6, 99]);
+        let min = a.minimum(b);
+        let max = a.maximum(b);
+        for i in 0..8 {
+            assert_eq!(min[i], a[i].min(b[i]));
+            assert_eq!(max[i], a[i].max(b[i]));
+        }
+
+        let a = f32x4::new([1.0, f32::NAN, -0.5, f32::NAN]);
+        let b = f32x4::new([f32::NAN, 2.0, -1.5, f32::NAN]);
+        let min = a.minimum(b);
+        let max = a.maximum(b);
+        for i in 0..4 {
+            // The std ones also follow the minNum/maxNum semantics
+            assert_eq!(min[i].to_bits(), a[i].min(b[i]).to_bits());
+            assert_eq!(max[i].to_bits(), a[i].max(b[i]).to_bits());
+        }
+    }
+
+    #[test]
+    fn horizontal_minmax() {
+        let v = i32x8::new([3, -1, 4, 1, -5, 9, 2, 10]);
+        assert_eq!(v.horizontal_max(), 10);
+        assert_eq!(v.horizontal_min(), -5);
+
+        let v = u8x4::new([1, 2, 3, 0]);
+        assert_eq!(v.horizontal_max(), 3);
+        assert_eq!(v.horizontal_min(), 0);
+
+        let eq = f32x4::splat(2.5);
+        assert_eq!(eq.horizontal_max(), 2.5);
+        assert_eq!(eq.horizontal_min(), 2.5);
+
+        let f = f32x4::new([f32::NAN, f32::NAN, f32::NAN, -1.0]);
+        assert_eq!(f.horizontal_max(), -1.0);
+        assert_eq!(f.horizontal_min(), -1.0);
+        assert!(f32x4::splat(f32::NAN).horizontal_max().is_nan());
+        assert!(f32x4::splat(f32::NAN).horizontal_min().is_nan());
+    }
+
+    #[test]
+    fn wrapping_arith() {
+        let a = wu8x4::splat(Wrapping(200));
+        assert_eq!(a + a, wu8x4::splat(Wrapping(144)));
+        assert_eq!(wu8x4::default() - a, wu8x4::splat(Wrapping(56)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn overflow_arith() {
+        let a = u8x4::splat(200);
+        let _ = a + a;
+    }
+
     const T: m32 = m32::TRUE;
     const F: m32 = m32::FALSE;
 
@@ -927,8 +2888,16 @@ mod tests {
         let v2 = u32x4::new([2, 3, 4, 5]);
 
         assert_eq!(v1.eq(v2), m32x4::new([F, T, F, F]));
+        assert_eq!(v1.ne(v2), m32x4::new([T, F, T, T]));
+        assert_eq!(v1.lt(v2), m32x4::new([T, F, F, F]));
         assert_eq!(v1.le(v2), m32x4::new([T, T, F, F]));
+        assert_eq!(v1.gt(v2), m32x4::new([F, F, T, T]));
         assert_eq!(v1.ge(v2), m32x4::new([F, T, T, T]));
+
+        // NaN is not equal to anything, not even itself
+        let f = f32x2::new([f32::NAN, 1.0]);
+        assert_eq!(f.eq(f), m32x2::new([F, T]));
+        assert_eq!(f.ne(f), m32x2::new([T, F]));
     }
 
     #[test]
@@ -942,4 +2911,299 @@ mod tests {
         let b2 = v1.blend(v2, [false, true, false, true]);
         assert_eq!(b1, b2);
     }
+
+    #[test]
+    fn any_all() {
+        let none = m32x4::splat(F);
+        assert!(!none.any());
+        assert!(!none.all());
+        assert!(none.none());
+
+        let all = m32x4::splat(T);
+        assert!(all.any());
+        assert!(all.all());
+        assert!(!all.none());
+
+        let mixed = m32x4::new([F, F, T, F]);
+        assert!(mixed.any());
+        assert!(!mixed.all());
+        assert!(!mixed.none());
+
+        // The padding of the tail must not produce false positives
+        let data = [1u32, 2, 3, 4, 5];
+        let found = data
+            .vectorize_pad(u32x4::default())
+            .map(|v: u32x4| v.eq(u32x4::default()))
+            .collect::<Vec<_>>();
+        assert!(found[0].none());
+        assert_eq!(found[1], m32x4::new([F, T, T, T]));
+        let any = data
+            .vectorize_pad(u32x4::splat(1))
+            .any(|v: u32x4| v.eq(u32x4::default()).any());
+        assert!(!any);
+    }
+
+    #[test]
+    fn bitmask() {
+        assert_eq!(m32x4::new([T, F, T, T]).bitmask(), 0b1101);
+        assert_eq!(m32x4::splat(F).bitmask(), 0);
+        assert_eq!(m32x4::splat(T).bitmask(), 0b1111);
+        assert_eq!(m8x32::splat(m8::TRUE).bitmask(), 0xffff_ffff);
+        assert_eq!(bx2::new([false, true]).bitmask(), 0b10);
+    }
+
+    #[test]
+    fn clamp() {
+        let lo = i32x4::new([0, 0, 0, 10]);
+        let hi = i32x4::new([10, 10, 10, 0]);
+        let v = i32x4::new([-5, 5, 15, 5]);
+        assert_eq!(v.clamp(lo, hi), i32x4::new([0, 5, 10, 0]));
+
+        let f = f32x4::new([f32::NAN, -1.0, 0.5, 2.0]);
+        let clamped = f.clamp(f32x4::splat(0.0), f32x4::splat(1.0));
+        assert_eq!(clamped, f32x4::new([0.0, 0.0, 0.5, 1.0]));
+    }
+
+    #[test]
+    fn cast() {
+        let f = f32x4::new([-1.9, 0.5, 3.99, -0.1]);
+        let i: i32x4 = f.cast();
+        assert_eq!(i, i32x4::new([-1, 0, 3, 0]));
+
+        let f = f32x4::new([1e10, -1e10, f32::INFINITY, f32::NAN]);
+        let i: i32x4 = f.cast();
+        assert_eq!(i, i32x4::new([i32::MAX, i32::MIN, i32::MAX, 0]));
+        let u: u32x4 = f32x4::new([-1.0, 1e10, 2.0, -0.0]).cast();
+        assert_eq!(u, u32x4::new([0, u32::MAX, 2, 0]));
+
+        let f: f64x2 = i64x2::new([-3, 1 << 40]).cast();
+        assert_eq!(f, f64x2::new([-3.0, (1u64 << 40) as f64]));
+
+        let n: u8x4 = u32x4::new([1, 255, 256, 300]).cast();
+        assert_eq!(n, u8x4::new([1, 255, 0, 44]));
+    }
+
+    #[test]
+    fn blend_max() {
+        let a = i16x8::new([1, -5, 3, 8, 0, 7, -2, 4]);
+        let b = i16x8::new([2, -6, 3, 1, -1, 9, -1, 4]);
+        assert_eq!(a.blend(b, a.lt(b)), a.maximum(b));
+        assert_eq!(a.blend(b, a.gt(b)), a.minimum(b));
+    }
+
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(u16x8::splat(3)));
+        assert!(!set.insert(u16x8::splat(3)));
+        assert!(set.insert(u16x8::new([3, 3, 3, 3, 3, 3, 3, 4])));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&u16x8::splat(3)));
+    }
+
+    #[test]
+    fn reduce_closure() {
+        let v = f32x8::new([1e8, 1.0, -1e8, 1.0, 0.5, 0.25, 3.0, -2.0]);
+        assert_eq!(v.reduce(|a, b| a + b), v.horizontal_sum());
+        let v = u32x4::new([3, 1, 4, 1]);
+        assert_eq!(v.reduce(|a, b| a + b), v.horizontal_sum());
+        assert_eq!(v.reduce(u32::max), 4);
+        assert_eq!(u32x2::new([3, 5]).reduce(|a, b| a * 10 + b), 35);
+
+        // The tree order
+        let v = u32x4::new([1, 2, 3, 4]);
+        let mut calls = Vec::new();
+        v.reduce(|a, b| {
+            calls.push((a, b));
+            a + b
+        });
+        assert_eq!(calls, vec![(1, 2), (3, 4), (3, 7)]);
+    }
+
+    #[test]
+    fn horizontal_bitwise() {
+        let v = u32x4::new([0, 0, 0x100, 0]);
+        assert_ne!(v.horizontal_or(), 0);
+        assert_eq!(v.horizontal_or(), 0x100);
+        assert_eq!(v.horizontal_and(), 0);
+        assert_eq!(v.horizontal_xor(), 0x100);
+
+        let v = i16x8::new([-1, -1, -1, 0, -1, -1, -1, -1]);
+        assert_eq!(v.horizontal_and(), 0);
+        assert_eq!(v.horizontal_or(), -1);
+        assert_eq!(v.horizontal_xor(), -1);
+        assert_eq!(i16x8::splat(-1).horizontal_and(), -1);
+
+        let m = m32x4::new([m32::FALSE, m32::TRUE, m32::FALSE, m32::FALSE]);
+        assert_eq!(m.horizontal_or(), m32::TRUE);
+        assert_eq!(m.horizontal_and(), m32::FALSE);
+    }
+
+    #[test]
+    fn lexicographic_ord() {
+        let mut vecs = vec![
+            u16x4::new([2, 0, 0, 0]),
+            u16x4::new([1, 2, 3, 5]),
+            u16x4::new([1, 2, 3, 4]),
+            u16x4::new([0, 9, 9, 9]),
+            u16x4::new([1, 2, 3, 4]),
+        ];
+        vecs.sort();
+        assert_eq!(
+            vecs,
+            vec![
+                u16x4::new([0, 9, 9, 9]),
+                u16x4::new([1, 2, 3, 4]),
+                u16x4::new([1, 2, 3, 4]),
+                u16x4::new([1, 2, 3, 5]),
+                u16x4::new([2, 0, 0, 0]),
+            ]
+        );
+
+        let a = i32x4::new([1, -5, 0, 0]);
+        let b = i32x4::new([1, 2, -3, 0]);
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert!(a < b);
+        // The methods still compare lane-wise
+        assert_eq!(
+            a.lt(b),
+            m32x4::new([m32::FALSE, m32::TRUE, m32::FALSE, m32::FALSE])
+        );
+
+        let f = f32x2::new([1.0, f32::NAN]);
+        assert_eq!(f.partial_cmp(&f), None);
+        assert!(f32x2::new([0.5, f32::NAN]) < f);
+    }
+
+    #[test]
+    fn float_eq() {
+        let a = f32x4::new([1.0, 2.0, 3.0, f32::NAN]);
+        assert_ne!(a, a);
+        assert_eq!(f32x4::splat(0.0), f32x4::splat(-0.0));
+    }
+
+    #[test]
+    fn display() {
+        let v = i16x8::new([1, -2, 3, -4, 5, -6, 7, -8]);
+        assert_eq!(v.to_string(), "i16x8(1, -2, 3, -4, 5, -6, 7, -8)");
+        assert_eq!(
+            format!("{:>3}", u32x4::new([1, 22, 333, 4])),
+            "u32x4(  1,  22, 333,   4)"
+        );
+        assert_eq!(wu8x2::splat(Wrapping(1)).to_string(), "wu8x2(1, 1)");
+        assert_eq!(format!("{:.1}", f32x2::new([0.5, 2.0])), "f32x2(0.5, 2.0)");
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(format!("{:x}", u16x2::new([255, 16])), "u16x2(ff, 10)");
+        assert_eq!(format!("{:#X}", u16x2::new([255, 16])), "u16x2(0xFF, 0x10)");
+        let mask = u8x4::new([1, 2, 3, 4]).gt(u8x4::splat(2));
+        assert_eq!(format!("{:02x}", mask), "m8x4(00, 00, ff, ff)");
+    }
+
+    #[test]
+    fn gather_vec() {
+        let ramp = (0..10u16).collect::<Vec<_>>();
+        let offsets = u32x4::new([0, 2, 4, 6]);
+        assert_eq!(V::gather_vec(&ramp, offsets), V::new([0, 2, 4, 6]));
+        assert_eq!(
+            f64x2::gather_vec([0.5, 1.5, 2.5], u32x2::new([2, 2])),
+            f64x2::splat(2.5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Gather index 10 out of bounds")]
+    fn gather_vec_oob() {
+        let ramp = (0..10u16).collect::<Vec<_>>();
+        V::gather_vec(&ramp, u32x4::new([0, 2, 10, 6]));
+    }
+
+    #[test]
+    fn gather_load_or() {
+        let input = [10u16, 20, 30];
+        let v = V::gather_load_or(input, [0, 5, 2, 3], 7);
+        assert_eq!(v, V::new([10, 7, 30, 7]));
+        let v = V::gather_load_or([0u16; 0], [0, 1, 2, 3], 1);
+        assert_eq!(v, V::splat(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong number of indexes")]
+    fn gather_load_or_wrong_len() {
+        V::gather_load_or([1, 2], [0, 1], 0);
+    }
+
+    #[test]
+    fn scatter_distinct() {
+        let mut data = [0u16; 6];
+        V::new([1, 2, 3, 4]).scatter_store(&mut data, [5, 0, 1, 3]);
+        assert_eq!(data, [2, 3, 0, 4, 0, 1]);
+        // Duplicates in disabled lanes are fine
+        V::splat(9).scatter_store_masked(&mut data, [2, 2, 2, 4], [true, false, false, true]);
+        assert_eq!(data, [2, 3, 9, 4, 9, 1]);
+    }
+
+    #[test]
+    fn scatter_vec() {
+        let mut data = [0u16; 5];
+        V::new([1, 2, 3, 4]).scatter_vec(&mut data, u32x4::new([3, 2, 1, 0]));
+        assert_eq!(data, [4, 3, 2, 1, 0]);
+        let gathered = V::gather_vec(data, u32x4::new([3, 2, 1, 0]));
+        assert_eq!(gathered, V::new([1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Scatter to duplicate index 2")]
+    fn scatter_vec_duplicate() {
+        V::splat(1).scatter_vec(&mut [0; 4], u32x4::new([0, 2, 1, 2]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Scatter to duplicate index 1")]
+    fn scatter_duplicate() {
+        let mut data = [0u16; 6];
+        V::new([1, 2, 3, 4]).scatter_store(&mut data, [0, 1, 2, 1]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Scatter to duplicate index 3")]
+    fn scatter_masked_duplicate() {
+        let mut data = [0u16; 6];
+        V::new([1, 2, 3, 4]).scatter_store_masked(
+            &mut data,
+            [3, 1, 3, 1],
+            [true, false, true, true],
+        );
+    }
+
+    #[test]
+    fn horizontal_sum_pairwise() {
+        let data = [1e8, -1e8, 1.0, 1.0, 1e8, -1e8, 1.0, 1.0];
+        let naive = data.iter().fold(0.0f32, |a, b| a + b);
+        assert_eq!(naive, 2.0);
+        assert_eq!(f32x8::new(data).horizontal_sum(), 4.0);
+    }
+
+    #[test]
+    fn dot() {
+        let a = (0..37).map(|i| i as f32).collect::<Vec<_>>();
+        let b = (0..37).map(|i| (i % 5) as f32 - 2.0).collect::<Vec<_>>();
+        let scalar = a.iter().zip(&b).map(|(a, b)| a * b).sum::<f32>();
+        assert_eq!(f32x8::dot(&a, &b), scalar);
+        assert_eq!(V::dot(&[1, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(V::dot(&[], &[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dot product of slices of different lengths")]
+    fn dot_different_lengths() {
+        V::dot(&[1, 2, 3], &[1, 2]);
+    }
 }
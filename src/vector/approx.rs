@@ -0,0 +1,109 @@
+//! Support for the approximate comparisons of the [`approx`] crate.
+//!
+//! The vectors are considered equal if all the lanes are.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+impl<A, B, const S: usize> AbsDiffEq for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + AbsDiffEq,
+    B::Epsilon: Copy,
+{
+    type Epsilon = B::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        B::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.data
+            .iter()
+            .zip(&other.data)
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<A, B, const S: usize> RelativeEq for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + RelativeEq,
+    B::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        B::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.data
+            .iter()
+            .zip(&other.data)
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<A, B, const S: usize> UlpsEq for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + UlpsEq,
+    B::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        B::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.data
+            .iter()
+            .zip(&other.data)
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, AbsDiffEq,
+    };
+
+    use crate::prelude::*;
+
+    #[test]
+    fn close() {
+        let a = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+        let b = a + f32x4::splat(1e-7);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+        assert_abs_diff_eq!(
+            f64x2::new([0.0, 1.0]),
+            f64x2::new([1e-3, 1.0]),
+            epsilon = 1e-2
+        );
+    }
+
+    #[test]
+    fn far() {
+        let a = f32x4::new([1.0, 2.0, 3.0, 4.0]);
+        // A single lane is enough to make them different
+        let b = a.replace(2, 3.1);
+        assert_relative_ne!(a, b);
+        assert!(!a.abs_diff_eq(&b, 0.01));
+        assert!(a.abs_diff_eq(&b, 0.2));
+        assert_relative_ne!(f32x2::splat(f32::NAN), f32x2::splat(f32::NAN));
+    }
+}
@@ -0,0 +1,506 @@
+//! Methods specific to the floating point vectors.
+//!
+//! These are inherent methods on the concrete [`Vector`] types, to mirror the inherent methods of
+//! the [`f32`] and [`f64`] scalars.
+
+use super::align::Align;
+use super::{Masked, Vector};
+use crate::inner::Repr;
+use crate::Mask;
+
+macro_rules! float {
+    ($($t: ty => $bits: ty),*) => {
+        $(
+            impl<A: Align, const S: usize> Vector<A, $t, S> {
+                /// Lane-wise absolute value.
+                ///
+                /// This clears the sign bit of each lane, therefore `-0.0` becomes `0.0` and
+                /// negative infinity becomes positive one. NaNs stay NaN.
+                #[inline]
+                pub fn abs(self) -> Self {
+                    self.map_lanes(<$t>::abs)
+                }
+
+                /// Lane-wise copy of the sign from `sign` to `self`.
+                ///
+                /// The result has the magnitude of `self` and the sign of `sign`. This is a pure
+                /// bit operation, so it works the same for `-0.0` and NaNs (their sign bit is
+                /// used and set as well).
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let v = f32x2::new([2.0, -3.0]).copysign(f32x2::new([-0.0, 1.0]));
+                /// assert_eq!(v, f32x2::new([-2.0, 3.0]));
+                /// ```
+                #[inline]
+                pub fn copysign(self, sign: Self) -> Self {
+                    const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                    Self::from_bits((self.to_bits() & !SIGN) | (sign.to_bits() & SIGN))
+                }
+
+                /// Lane-wise sign of the values.
+                ///
+                /// This follows the scalar `signum`. Each lane is `1.0` if the value is positive,
+                /// `+0.0` or positive infinity and `-1.0` if it is negative, `-0.0` or negative
+                /// infinity. Note that zero doesn't produce `0.0`. NaNs stay NaN.
+                #[inline]
+                pub fn signum(self) -> Self {
+                    self.map_lanes(<$t>::signum)
+                }
+
+                /// Lane-wise conversion from radians to degrees.
+                ///
+                /// This uses the same constant as the scalar `to_degrees`, so the results are
+                /// exactly the same.
+                #[inline]
+                pub fn to_degrees(self) -> Self {
+                    self.map_lanes(<$t>::to_degrees)
+                }
+
+                /// Lane-wise conversion from degrees to radians.
+                ///
+                /// This uses the same constant as the scalar `to_radians`, so the results are
+                /// exactly the same.
+                #[inline]
+                pub fn to_radians(self) -> Self {
+                    self.map_lanes(<$t>::to_radians)
+                }
+
+                /// Lane-wise check for the sign bit.
+                ///
+                /// Like the scalar `is_sign_negative`, this is true for `-0.0` and NaNs with the
+                /// sign bit too.
+                #[inline]
+                pub fn is_sign_negative(self) -> <Self as Masked>::Mask {
+                    self.classify(<$t>::is_sign_negative)
+                }
+
+                /// Lane-wise check for NaN values.
+                #[inline]
+                pub fn is_nan(self) -> <Self as Masked>::Mask {
+                    self.classify(<$t>::is_nan)
+                }
+
+                /// Lane-wise check for positive or negative infinity.
+                #[inline]
+                pub fn is_infinite(self) -> <Self as Masked>::Mask {
+                    self.classify(<$t>::is_infinite)
+                }
+
+                /// Lane-wise check for values that are neither infinite nor NaN.
+                #[inline]
+                pub fn is_finite(self) -> <Self as Masked>::Mask {
+                    self.classify(<$t>::is_finite)
+                }
+
+                #[inline(always)]
+                fn classify<F: Fn($t) -> bool>(self, f: F) -> <Self as Masked>::Mask {
+                    let mut result = <Self as Masked>::Mask::splat(Mask::FALSE);
+                    for i in 0..S {
+                        result[i] = <$t as Repr>::Mask::from_bool(f(self[i]));
+                    }
+                    result
+                }
+
+                /// Reinterprets the lanes as their raw bits.
+                ///
+                /// This is the same as calling `to_bits` on each lane. It allows manipulating
+                /// the bits of the float (eg. by the bitwise operators).
+                #[inline]
+                pub fn to_bits(self) -> Vector<A, $bits, S> {
+                    let mut result = Vector::splat(0);
+                    for i in 0..S {
+                        result[i] = self[i].to_bits();
+                    }
+                    result
+                }
+
+                /// Reinterprets the raw bits as floats.
+                ///
+                /// This is the inverse of [`to_bits`][Vector::to_bits].
+                #[inline]
+                pub fn from_bits(bits: Vector<A, $bits, S>) -> Self {
+                    let mut result = Self::splat(0.0);
+                    for i in 0..S {
+                        result[i] = <$t>::from_bits(bits[i]);
+                    }
+                    result
+                }
+
+                /// Lane-wise square root.
+                ///
+                /// The result is correctly rounded, like the scalar `sqrt`. Negative lanes (other
+                /// than `-0.0`) produce NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let v = f32x4::new([1.0, 4.0, 9.0, -1.0]).sqrt();
+                /// assert_eq!(&v[..3], &[1.0, 2.0, 3.0]);
+                /// assert!(v[3].is_nan());
+                /// ```
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn sqrt(self) -> Self {
+                    self.map_lanes(<$t>::sqrt)
+                }
+
+                /// Lane-wise cube root.
+                ///
+                /// Unlike the square root, this is defined for negative lanes too (eg. `-8.0`
+                /// produces `-2.0`). The precision is the same as of the scalar `cbrt`.
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn cbrt(self) -> Self {
+                    self.map_lanes(<$t>::cbrt)
+                }
+
+                /// Lane-wise length of the hypotenuse, `sqrt(self² + other²)`.
+                ///
+                /// Like the scalar `hypot`, the computation avoids overflow (and underflow) of the
+                /// intermediate squares, so it works for large values where the naive formula
+                /// would produce infinity. The precision is the same as of the scalar `hypot`.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let v = f32x2::new([3.0, 3e30]).hypot(f32x2::new([4.0, 4e30]));
+                /// assert_eq!(v, f32x2::new([5.0, 5e30]));
+                /// ```
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn hypot(self, other: Self) -> Self {
+                    self.zip_lanes(other, <$t>::hypot)
+                }
+
+                /// Lane-wise raising to an integer power.
+                ///
+                /// This is computed by exponentiation by squaring on whole vectors, negative
+                /// exponents take the reciprocal at the end. The rounding errors may differ
+                /// slightly from the scalar `powi`. Any value raised to `0` is `1.0`, including
+                /// NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let v = f32x2::new([2.0, -3.0]);
+                /// assert_eq!(v.powi(3), f32x2::new([8.0, -27.0]));
+                /// assert_eq!(v.powi(-1), f32x2::new([0.5, -1.0 / 3.0]));
+                /// ```
+                #[inline]
+                pub fn powi(self, exp: i32) -> Self {
+                    let mut base = self;
+                    let mut rest = exp.unsigned_abs();
+                    let mut result = Self::splat(1.0);
+                    while rest > 0 {
+                        if rest & 1 == 1 {
+                            result *= base;
+                        }
+                        base *= base;
+                        rest >>= 1;
+                    }
+                    if exp < 0 {
+                        Self::splat(1.0) / result
+                    } else {
+                        result
+                    }
+                }
+
+                /// Lane-wise rounding towards negative infinity.
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn floor(self) -> Self {
+                    self.map_lanes(<$t>::floor)
+                }
+
+                /// Lane-wise rounding towards positive infinity.
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn ceil(self) -> Self {
+                    self.map_lanes(<$t>::ceil)
+                }
+
+                /// Lane-wise rounding to the nearest integer.
+                ///
+                /// Like the scalar `round`, the half-way cases are rounded away from zero (eg.
+                /// `2.5` to `3.0` and `-1.5` to `-2.0`).
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn round(self) -> Self {
+                    self.map_lanes(<$t>::round)
+                }
+
+                /// Lane-wise rounding towards zero (dropping the fractional part).
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn trunc(self) -> Self {
+                    self.map_lanes(<$t>::trunc)
+                }
+
+                /// Lane-wise fractional part.
+                ///
+                /// This is `self - self.trunc()`, therefore the result has the same sign as the
+                /// input (eg. `-1.25` produces `-0.25`).
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn fract(self) -> Self {
+                    self.map_lanes(<$t>::fract)
+                }
+
+                /// Lane-wise Euclidean remainder.
+                ///
+                /// Unlike the `%` operator, the result is never negative (for finite inputs). This
+                /// is useful for wrapping values into a range, eg. phases:
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let phase = f32x2::new([-0.25, 1.5]).rem_euclid(f32x2::splat(1.0));
+                /// assert_eq!(phase, f32x2::new([0.75, 0.5]));
+                /// ```
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn rem_euclid(self, rhs: Self) -> Self {
+                    self.zip_lanes(rhs, <$t>::rem_euclid)
+                }
+
+                /// Fused multiply-add, lane-wise `self * a + b`.
+                ///
+                /// Like the scalar `mul_add`, this is computed with a single rounding (and
+                /// therefore may differ in the last bits from doing the multiplication and
+                /// addition separately). This is fast on targets with the FMA instructions
+                /// available (note that these may need to be enabled, either at compile time or
+                /// through multiversioning). On others, it falls back to a correct, but
+                /// significantly slower, software implementation.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// let v = f32x2::new([1.0, 2.0]);
+                /// assert_eq!(v.mul_add(f32x2::splat(3.0), f32x2::splat(0.5)), [3.5, 6.5]);
+                /// ```
+                #[cfg(feature = "std")]
+                #[inline]
+                pub fn mul_add(self, a: Self, b: Self) -> Self {
+                    let mut result = self;
+                    for i in 0..S {
+                        result[i] = self[i].mul_add(a[i], b[i]);
+                    }
+                    result
+                }
+            }
+        )*
+    };
+}
+
+float!(f32 => u32, f64 => u64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn copysign() {
+        let v = f32x4::new([2.0, 3.0, 4.0, 5.0]).copysign(f32x4::new([-1.0, 1.0, -1.0, 1.0]));
+        assert_eq!(v, f32x4::new([-2.0, 3.0, -4.0, 5.0]));
+
+        let v =
+            f64x4::new([1.0, -1.0, 0.0, f64::NAN]).copysign(f64x4::new([-0.0, 0.0, -2.0, -1.0]));
+        assert_eq!(v[0], -1.0);
+        assert_eq!(v[1], 1.0);
+        assert!(v[2] == 0.0 && v[2].is_sign_negative());
+        assert!(v[3].is_nan() && v[3].is_sign_negative());
+    }
+
+    #[test]
+    fn signum() {
+        let v = f32x4::new([-3.5, 2.0, 0.0, f32::NAN]);
+        let s = v.signum();
+        assert_eq!(&s[..3], &[-1.0, 1.0, 1.0]);
+        assert!(s[3].is_nan());
+        assert_eq!(f32x2::new([-0.0, f32::NEG_INFINITY]).signum(), [-1.0, -1.0]);
+
+        assert_eq!(v.is_sign_negative().bitmask(), 0b0001);
+        let v = f64x2::new([-0.0, -f64::NAN]);
+        assert_eq!(v.is_sign_negative().bitmask(), 0b11);
+    }
+
+    #[test]
+    fn degrees_radians() {
+        use core::f32::consts::PI;
+        let deg = f32x4::new([0.0, 90.0, -180.0, 45.5]);
+        let rad = deg.to_radians();
+        assert_eq!(rad[..3], [0.0, PI / 2.0, -PI]);
+        for i in 0..4 {
+            assert_eq!(rad[i], deg[i].to_radians());
+            assert_eq!(rad.to_degrees()[i], rad[i].to_degrees());
+        }
+        let back = rad.to_degrees();
+        for i in 0..4 {
+            assert!((back[i] - deg[i]).abs() <= 1e-5 * deg[i].abs());
+        }
+
+        let v = f64x2::new([1.0, -2.5]);
+        let back = v.to_radians().to_degrees();
+        assert!((back - v).abs().horizontal_max() <= 1e-14);
+    }
+
+    #[test]
+    fn abs() {
+        let v = f32x4::new([-1.5, -0.0, f32::NEG_INFINITY, 2.0]);
+        let a = v.abs();
+        assert_eq!(a, f32x4::new([1.5, 0.0, f32::INFINITY, 2.0]));
+        assert!(a[1].is_sign_positive());
+        assert!(f64x2::splat(-f64::NAN).abs()[0].is_nan());
+    }
+
+    #[test]
+    fn bits() {
+        let v = f32x4::new([1.0, -0.0, f32::NAN, f32::NEG_INFINITY]);
+        let bits = v.to_bits();
+        assert_eq!(
+            bits,
+            u32x4::new([0x3f80_0000, 0x8000_0000, f32::NAN.to_bits(), 0xff80_0000])
+        );
+        let back = f32x4::from_bits(bits);
+        assert_eq!(back.to_bits(), bits);
+        assert!(back[1].is_sign_negative());
+        assert!(back[2].is_nan());
+
+        let d = f64x2::new([-2.5, f64::MIN_POSITIVE]);
+        assert_eq!(f64x2::from_bits(d.to_bits()), d);
+        // Clearing the sign bit
+        let abs = f64x2::from_bits(d.to_bits() & !(1 << 63));
+        assert_eq!(abs, d.abs());
+    }
+
+    #[test]
+    fn classify() {
+        const T: m32 = m32::TRUE;
+        const F: m32 = m32::FALSE;
+        let v = f32x4::new([f32::NAN, f32::NEG_INFINITY, 1.0, -0.0]);
+        assert_eq!(v.is_nan(), m32x4::new([T, F, F, F]));
+        assert_eq!(v.is_infinite(), m32x4::new([F, T, F, F]));
+        assert_eq!(v.is_finite(), m32x4::new([F, F, T, T]));
+
+        // Scrubbing the bad values
+        let clean = v.blend(f32x4::splat(0.0), !v.is_finite());
+        assert_eq!(clean, f32x4::new([0.0, 0.0, 1.0, -0.0]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sqrt() {
+        let v = f32x4::new([1.0, 4.0, 9.0, 16.0]);
+        assert_eq!(v.sqrt(), f32x4::new([1.0, 2.0, 3.0, 4.0]));
+
+        let v = f64x2::new([2.0, -4.0]).sqrt();
+        assert_eq!(v[0], 2.0f64.sqrt());
+        assert!(v[1].is_nan());
+        assert!(f64x2::splat(-0.0).sqrt()[0].is_sign_negative());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn cbrt() {
+        let v = f32x4::new([27.0, -8.0, 0.0, 1e-3]).cbrt();
+        assert_eq!(&v[..3], &[3.0, -2.0, 0.0]);
+        assert!((v[3] - 0.1).abs() < 1e-7);
+        assert_eq!(f64x2::splat(27.0).cbrt(), f64x2::splat(3.0));
+        assert!(f64x2::splat(f64::NAN).cbrt()[0].is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hypot() {
+        assert_eq!(
+            f32x4::splat(3.0).hypot(f32x4::splat(4.0)),
+            f32x4::splat(5.0)
+        );
+        let a = f32x4::new([3e30, -3e-30, 0.0, f32::INFINITY]);
+        let b = f32x4::new([4e30, 4e-30, -2.0, f32::NAN]);
+        let h = a.hypot(b);
+        // The naive formula overflows and underflows
+        assert!((a * a + b * b).sqrt()[0].is_infinite());
+        assert_eq!((a * a + b * b).sqrt()[1], 0.0);
+        for i in 0..4 {
+            assert_eq!(h[i], a[i].hypot(b[i]));
+        }
+        assert_eq!(&h[..3], &[5e30, 5e-30, 2.0]);
+    }
+
+    #[test]
+    fn powi() {
+        assert_eq!(f32x4::splat(2.0).powi(10), f32x4::splat(1024.0));
+        let v = f32x4::new([0.0, -1.5, f32::NAN, 3.0]);
+        assert_eq!(v.powi(0), f32x4::splat(1.0));
+        assert_eq!(v.powi(1).to_bits(), v.to_bits());
+        assert_eq!(f64x2::new([2.0, -4.0]).powi(-2), f64x2::new([0.25, 0.0625]));
+
+        let v = f64x4::new([1.1, -0.7, 3.3, 12.5]);
+        for exp in -7..=7 {
+            let p = v.powi(exp);
+            for i in 0..4 {
+                let expected = v[i].powi(exp);
+                assert!((p[i] - expected).abs() <= expected.abs() * 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rounding() {
+        let v = f32x4::new([1.4, 1.6, -1.5, 2.5]);
+        assert_eq!(v.floor(), f32x4::new([1.0, 1.0, -2.0, 2.0]));
+        assert_eq!(v.ceil(), f32x4::new([2.0, 2.0, -1.0, 3.0]));
+        assert_eq!(v.round(), f32x4::new([1.0, 2.0, -2.0, 3.0]));
+        assert_eq!(v.trunc(), f32x4::new([1.0, 1.0, -1.0, 2.0]));
+
+        // Negative values keep the sign even when rounded to zero
+        let z = f64x2::new([-0.4, -0.6]);
+        assert!(z.trunc()[0].is_sign_negative());
+        assert!(z.ceil()[1].is_sign_negative());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn fract() {
+        let v = f32x4::new([-1.25, 1.25, -3.0, 0.5]);
+        assert_eq!(v.fract(), f32x4::new([-0.25, 0.25, 0.0, 0.5]));
+        for i in 0..4 {
+            assert_eq!(v.fract()[i], v[i].fract());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rem_euclid() {
+        let v = f64x4::new([-1.25, 1.25, -3.0, 7.5]);
+        let r = v.rem_euclid(f64x4::new([1.0, 1.0, 2.0, -2.0]));
+        assert_eq!(r, f64x4::new([0.75, 0.25, 1.0, 1.5]));
+        // The % operator keeps the sign of the dividend
+        assert_eq!(v % f64x4::splat(1.0), f64x4::new([-0.25, 0.25, -0.0, 0.5]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn mul_add() {
+        // Horner evaluation of 2x^3 - 3x^2 + 0.5x + 1
+        let coefs = [2.0, -3.0, 0.5, 1.0];
+        let x = f32x4::new([-1.5, 0.1, 0.7, 3.0]);
+        let mut fused = f32x4::splat(0.0);
+        let mut separate = f32x4::splat(0.0);
+        for &c in &coefs {
+            fused = fused.mul_add(x, f32x4::splat(c));
+            separate = separate * x + c;
+        }
+        for i in 0..4 {
+            assert!((fused[i] - separate[i]).abs() <= 1e-5 * separate[i].abs().max(1.0));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Splitting vectors into halves and joining them back.
+//!
+//! The halves have half the number of lanes and half the alignment, so for the usual types the
+//! halves are the usual types too. As the lane count can't be computed in the type yet, these are
+//! implemented for the concrete combinations of alignments and lane counts of the vector types.
+
+use super::align::*;
+use super::Vector;
+use crate::inner::Repr;
+
+macro_rules! halves {
+    ($($align: ty, $s: literal => $half_align: ty, $h: literal;)*) => {
+        $(
+            impl<B: Repr> Vector<$align, B, $s> {
+                /// Splits the vector into the lower and upper half of the lanes.
+                ///
+                /// The halves have half the alignment too, so eg.
+                /// [`u32x8`][crate::types::u32x8] splits into two
+                /// [`u32x4`][crate::types::u32x4].
+                ///
+                /// This is not named `split`, as that would shadow the method of slices the
+                /// vector dereferences to.
+                #[inline]
+                pub fn split_halves(self) -> (Vector<$half_align, B, $h>, Vector<$half_align, B, $h>) {
+                    (Vector::new(&self.data[..$h]), Vector::new(&self.data[$h..]))
+                }
+
+                /// Joins two halves into one vector.
+                ///
+                /// This is the inverse of [`split_halves`][Vector::split_halves], the `lo` goes to the lower
+                /// half of the lanes and `hi` to the upper one.
+                #[inline]
+                pub fn concat(lo: Vector<$half_align, B, $h>, hi: Vector<$half_align, B, $h>) -> Self {
+                    Self::from_fn(|i| if i < $h { lo[i] } else { hi[i - $h] })
+                }
+            }
+        )*
+    };
+}
+
+halves! {
+    Align2, 2 => Align1, 1;
+    Align4, 2 => Align2, 1;
+    Align4, 4 => Align2, 2;
+    Align8, 2 => Align4, 1;
+    Align8, 4 => Align4, 2;
+    Align8, 8 => Align4, 4;
+    Align16, 2 => Align8, 1;
+    Align16, 4 => Align8, 2;
+    Align16, 8 => Align8, 4;
+    Align16, 16 => Align8, 8;
+    Align32, 4 => Align16, 2;
+    Align32, 8 => Align16, 4;
+    Align32, 16 => Align16, 8;
+    Align32, 32 => Align16, 16;
+    Align64, 8 => Align32, 4;
+    Align64, 16 => Align32, 8;
+    Align128, 16 => Align64, 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn split_halves() {
+        let v = u32x8::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let (lo, hi) = v.split_halves();
+        assert_eq!(lo, u32x4::new([1, 2, 3, 4]));
+        assert_eq!(hi, u32x4::new([5, 6, 7, 8]));
+
+        let v = u8x32::from_fn(|i| i as u8);
+        let (lo, hi) = v.split_halves();
+        assert_eq!(lo, u8x16::from_fn(|i| i as u8));
+        assert_eq!(hi, u8x16::from_fn(|i| i as u8 + 16));
+
+        let (lo, hi) = f64x2::new([1.5, -2.5]).split_halves();
+        assert_eq!((lo[0], hi[0]), (1.5, -2.5));
+
+        // The slice split is still reachable
+        let v = u32x4::new([1, 0, 2, 3]);
+        assert_eq!(v.split(|l| *l == 0).count(), 2);
+    }
+
+    #[test]
+    fn concat() {
+        let v = u32x8::concat(u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8]));
+        assert_eq!(v, u32x8::new([1, 2, 3, 4, 5, 6, 7, 8]));
+
+        let v = i16x16::from_fn(|i| i as i16 - 8);
+        let (lo, hi) = v.split_halves();
+        assert_eq!(i16x16::concat(lo, hi), v);
+        assert_eq!(
+            m8x32::concat(m8x16::splat(m8::TRUE), m8x16::default()).bitmask(),
+            0xffff
+        );
+    }
+}
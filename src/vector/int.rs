@@ -0,0 +1,543 @@
+//! Methods specific to the integer vectors.
+//!
+//! These are inherent methods on the concrete [`Vector`] types, as they correspond to inherent
+//! methods of the integer scalars and there's no trait in the standard library to bound them by.
+
+use core::num::Wrapping;
+use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
+
+use super::align::Align;
+use super::{Masked, Vector};
+use crate::inner::Repr;
+use crate::Mask;
+
+macro_rules! int {
+    ($($t: ty),*) => {
+        $(
+            impl<A: Align, const S: usize> Vector<A, $t, S> {
+                /// Produces a vector of consecutive values, starting with `start` in the first
+                /// lane.
+                ///
+                /// The values wrap around if they don't fit into the type.
+                #[inline]
+                pub fn iota(start: $t) -> Self {
+                    Self::from_fn(|i| start.wrapping_add(i as $t))
+                }
+
+                /// Produces a vector holding the index of each lane (`[0, 1, 2, ...]`).
+                ///
+                /// This is the usual base for computing indices for
+                /// [`gather_load`][Vector::gather_load] and similar.
+                #[inline]
+                pub fn lane_ids() -> Self {
+                    Self::iota(0)
+                }
+
+                /// Lane-wise saturating addition.
+                ///
+                /// Instead of overflowing, the lanes stick at the maximum or minimum value.
+                #[inline]
+                pub fn saturating_add(self, other: Self) -> Self {
+                    self.zip_lanes(other, <$t>::saturating_add)
+                }
+
+                /// Lane-wise saturating subtraction.
+                ///
+                /// Instead of overflowing, the lanes stick at the maximum or minimum value.
+                #[inline]
+                pub fn saturating_sub(self, other: Self) -> Self {
+                    self.zip_lanes(other, <$t>::saturating_sub)
+                }
+
+                /// Lane-wise addition with overflow detection.
+                ///
+                /// Returns the wrapped results and a mask of the lanes that overflowed, like the
+                /// scalar `overflowing_add` does for a single value.
+                #[inline]
+                pub fn overflowing_add(self, other: Self) -> (Self, <Self as Masked>::Mask) {
+                    self.overflowing(other, <$t>::overflowing_add)
+                }
+
+                /// Lane-wise subtraction with overflow detection.
+                ///
+                /// Returns the wrapped results and a mask of the lanes that overflowed, like the
+                /// scalar `overflowing_sub` does for a single value.
+                #[inline]
+                pub fn overflowing_sub(self, other: Self) -> (Self, <Self as Masked>::Mask) {
+                    self.overflowing(other, <$t>::overflowing_sub)
+                }
+
+                #[inline(always)]
+                fn overflowing<F>(self, other: Self, f: F) -> (Self, <Self as Masked>::Mask)
+                where
+                    F: Fn($t, $t) -> ($t, bool),
+                {
+                    let mut result = self;
+                    let mut overflow = <Self as Masked>::Mask::splat(Mask::FALSE);
+                    for i in 0..S {
+                        let (r, o) = f(self[i], other[i]);
+                        result[i] = r;
+                        overflow[i] = <$t as Repr>::Mask::from_bool(o);
+                    }
+                    (result, overflow)
+                }
+
+                /// Lane-wise number of ones in the binary representation.
+                ///
+                /// The results are stored in the lanes of the same type.
+                #[inline]
+                pub fn count_ones(self) -> Self {
+                    self.map_lanes(|v| v.count_ones() as $t)
+                }
+
+                /// Lane-wise number of leading zeros in the binary representation.
+                ///
+                /// Like with the scalars, a lane with zero produces the bit width of the type.
+                #[inline]
+                pub fn leading_zeros(self) -> Self {
+                    self.map_lanes(|v| v.leading_zeros() as $t)
+                }
+
+                /// Lane-wise number of trailing zeros in the binary representation.
+                ///
+                /// Like with the scalars, a lane with zero produces the bit width of the type.
+                #[inline]
+                pub fn trailing_zeros(self) -> Self {
+                    self.map_lanes(|v| v.trailing_zeros() as $t)
+                }
+
+                /// Rotates the bits of each lane to the left by `n`.
+                ///
+                /// Like with the scalars, `n` is taken modulo the bit width of the type and the
+                /// bits shifted out on the left come back on the right. Not to be confused with
+                /// [`rotate_lanes_left`][Vector::rotate_lanes_left], which moves whole lanes.
+                ///
+                /// This is not named `rotate_left` like the scalar method, because that would
+                /// shadow the in-place lane rotation of slices the vector dereferences to.
+                #[inline]
+                pub fn rotate_bits_left(self, n: u32) -> Self {
+                    self.map_lanes(|v| v.rotate_left(n))
+                }
+
+                /// Rotates the bits of each lane to the right by `n`.
+                ///
+                /// Like with the scalars, `n` is taken modulo the bit width of the type. Not to be
+                /// confused with [`rotate_lanes_right`][Vector::rotate_lanes_right], which moves
+                /// whole lanes.
+                #[inline]
+                pub fn rotate_bits_right(self, n: u32) -> Self {
+                    self.map_lanes(|v| v.rotate_right(n))
+                }
+
+                /// Reverses the byte order of each lane.
+                #[inline]
+                pub fn swap_bytes(self) -> Self {
+                    self.map_lanes(<$t>::swap_bytes)
+                }
+
+                /// Converts each lane to big endian from the target's endianness.
+                ///
+                /// On big endian targets this is a no-op, on little endian ones the bytes are
+                /// swapped.
+                #[inline]
+                pub fn to_be(self) -> Self {
+                    self.map_lanes(<$t>::to_be)
+                }
+
+                /// Converts each lane to little endian from the target's endianness.
+                ///
+                /// On little endian targets this is a no-op, on big endian ones the bytes are
+                /// swapped.
+                #[inline]
+                pub fn to_le(self) -> Self {
+                    self.map_lanes(<$t>::to_le)
+                }
+
+                /// Converts each lane from big endian to the target's endianness.
+                ///
+                /// Like with the scalars, this is an associated function, the inverse of
+                /// [`to_be`][Vector::to_be].
+                #[inline]
+                pub fn from_be(v: Self) -> Self {
+                    v.map_lanes(<$t>::from_be)
+                }
+
+                /// Converts each lane from little endian to the target's endianness.
+                ///
+                /// Like with the scalars, this is an associated function, the inverse of
+                /// [`to_le`][Vector::to_le].
+                #[inline]
+                pub fn from_le(v: Self) -> Self {
+                    v.map_lanes(<$t>::from_le)
+                }
+            }
+        )*
+    };
+}
+
+int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Shifts by a `u32` count, like the `rotate_bits_*` and the `checked_*`/`wrapping_*` shifts of
+/// the scalars take.
+///
+/// Shifts by `i32` are provided too, as that's what an unsuffixed literal (`v << 3`) falls back to
+/// when there are multiple options. The shift by the lane type itself comes from the generic
+/// implementation of the operators.
+macro_rules! shift_by {
+    ($r: ty => $($t: ty),*) => {
+        $(
+            impl<A: Align, const S: usize> Shl<$r> for Vector<A, $t, S> {
+                type Output = Self;
+                #[inline]
+                fn shl(self, rhs: $r) -> Self {
+                    self.map_lanes(|v| v << rhs)
+                }
+            }
+
+            impl<A: Align, const S: usize> Shr<$r> for Vector<A, $t, S> {
+                type Output = Self;
+                #[inline]
+                fn shr(self, rhs: $r) -> Self {
+                    self.map_lanes(|v| v >> rhs)
+                }
+            }
+
+            impl<A: Align, const S: usize> ShlAssign<$r> for Vector<A, $t, S> {
+                #[inline]
+                fn shl_assign(&mut self, rhs: $r) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl<A: Align, const S: usize> ShrAssign<$r> for Vector<A, $t, S> {
+                #[inline]
+                fn shr_assign(&mut self, rhs: $r) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+shift_by!(u32 => u8, u16, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+shift_by!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, i64, i128, isize);
+
+macro_rules! signed {
+    ($($t: ty),*) => {
+        $(
+            impl<A: Align, const S: usize> Vector<A, $t, S> {
+                /// Lane-wise absolute value.
+                ///
+                /// Unlike the scalar `abs`, this never panics. The absolute value of the minimal
+                /// value can't be represented and it wraps around to itself (eg. `i32::MIN`),
+                /// consistently with the [`Wrapping`] based vectors.
+                #[inline]
+                pub fn abs(self) -> Self {
+                    self.map_lanes(|v| v.wrapping_abs())
+                }
+
+                /// Lane-wise sign of the values.
+                ///
+                /// Each lane is `-1` for negative values, `0` for zero and `1` for positive ones.
+                #[inline]
+                pub fn signum(self) -> Self {
+                    self.map_lanes(<$t>::signum)
+                }
+
+                /// Lane-wise check for negative values.
+                #[inline]
+                pub fn is_negative(self) -> <Self as Masked>::Mask {
+                    self.lt(Self::splat(0))
+                }
+            }
+        )*
+    };
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! wrapping_signed {
+    ($($t: ty),*) => {
+        $(
+            impl<A: Align, const S: usize> Vector<A, Wrapping<$t>, S> {
+                /// Lane-wise absolute value.
+                ///
+                /// The absolute value of the minimal value wraps around to itself.
+                #[inline]
+                pub fn abs(self) -> Self {
+                    self.map_lanes(|v| Wrapping(v.0.wrapping_abs()))
+                }
+            }
+        )*
+    };
+}
+
+wrapping_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! widen_narrow {
+    ($($narrow: ty => $wide: ty),*) => {
+        $(
+            widen_narrow!(@sizes $narrow => $wide, 2 => 1, 4 => 2, 8 => 4, 16 => 8, 32 => 16);
+        )*
+    };
+    (@sizes $narrow: ty => $wide: ty, $($s: literal => $h: literal),*) => {
+        $(
+            impl<A: Align> Vector<A, $narrow, $s> {
+                /// Converts the vector into two halves of the next larger integer type.
+                ///
+                /// The first vector holds the lower half of the lanes, the other the upper half.
+                /// The values are zero-extended for unsigned types and sign-extended for signed
+                /// ones, so they stay the same. The halves have the same size in bytes as the
+                /// original vector (eg. [`u16x8`][crate::types::u16x8] widens into two
+                /// [`u32x4`][crate::types::u32x4]).
+                #[inline]
+                pub fn widen(self) -> (Vector<A, $wide, $h>, Vector<A, $wide, $h>) {
+                    let mut lo = Vector::splat(0);
+                    let mut hi = Vector::splat(0);
+                    for i in 0..$h {
+                        lo[i] = self[i].into();
+                        hi[i] = self[i + $h].into();
+                    }
+                    (lo, hi)
+                }
+
+                /// Packs two vectors of the next larger integer type into one vector.
+                ///
+                /// This is the counterpart of [`widen`][Vector::widen], `lo` provides the lower
+                /// half of the lanes and `hi` the upper half. Values that don't fit into the
+                /// smaller type saturate at its minimum or maximum instead of wrapping around.
+                #[inline]
+                pub fn narrow_saturate(
+                    lo: Vector<A, $wide, $h>,
+                    hi: Vector<A, $wide, $h>,
+                ) -> Self {
+                    #[inline(always)]
+                    fn saturate(v: $wide) -> $narrow {
+                        v.clamp(<$narrow>::MIN.into(), <$narrow>::MAX.into()) as $narrow
+                    }
+                    let mut result = Self::splat(0);
+                    for i in 0..$h {
+                        result[i] = saturate(lo[i]);
+                        result[i + $h] = saturate(hi[i]);
+                    }
+                    result
+                }
+            }
+        )*
+    };
+}
+
+widen_narrow!(
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn iota() {
+        assert_eq!(u32x8::lane_ids(), u32x8::new([0, 1, 2, 3, 4, 5, 6, 7]));
+        assert_eq!(u32x4::iota(10), u32x4::new([10, 11, 12, 13]));
+        assert_eq!(i8x4::iota(-2), i8x4::new([-2, -1, 0, 1]));
+        assert_eq!(u8x4::iota(254), u8x4::new([254, 255, 0, 1]));
+    }
+
+    #[test]
+    fn saturating() {
+        let v = u8x16::splat(200);
+        assert_eq!(v.saturating_add(v), u8x16::splat(255));
+        assert_eq!(u8x16::splat(1).saturating_sub(v), u8x16::splat(0));
+
+        let a = i8x4::new([100, -100, 5, i8::MIN]);
+        let b = i8x4::new([100, -100, -10, -1]);
+        assert_eq!(a.saturating_add(b), i8x4::new([127, -128, -5, i8::MIN]));
+        assert_eq!(a.saturating_sub(-b), i8x4::new([127, -128, -5, i8::MIN]));
+        assert_eq!(a.saturating_sub(b), i8x4::new([0, 0, 15, -127]));
+    }
+
+    #[test]
+    fn overflowing() {
+        let a = u8x4::new([250, 100, 255, 0]);
+        let b = u8x4::new([10, 100, 1, 0]);
+        let (sum, overflow) = a.overflowing_add(b);
+        assert_eq!(sum, u8x4::new([4, 200, 0, 0]));
+        assert_eq!(overflow.bitmask(), 0b0101);
+        let (diff, overflow) = b.overflowing_sub(a);
+        assert_eq!(diff, u8x4::new([16, 0, 2, 0]));
+        assert_eq!(overflow.bitmask(), 0b0101);
+
+        let a = i32x4::new([i32::MAX - 1, i32::MAX, i32::MIN, -5]);
+        let (sum, overflow) = a.overflowing_add(i32x4::splat(1));
+        assert_eq!(sum, i32x4::new([i32::MAX, i32::MIN, i32::MIN + 1, -4]));
+        assert_eq!(overflow.bitmask(), 0b0010);
+        let (_, overflow) = a.overflowing_sub(i32x4::splat(1));
+        assert_eq!(overflow.bitmask(), 0b0100);
+    }
+
+    #[test]
+    fn bit_counts() {
+        let v = u32x4::new([0, 1, 0xf0, u32::MAX]);
+        assert_eq!(v.count_ones(), u32x4::new([0, 1, 4, 32]));
+        assert_eq!(v.leading_zeros(), u32x4::new([32, 31, 24, 0]));
+        assert_eq!(v.trailing_zeros(), u32x4::new([32, 0, 4, 0]));
+
+        let v = i8x4::new([-1, 0, i8::MIN, 6]);
+        assert_eq!(v.count_ones(), i8x4::new([8, 0, 1, 2]));
+        assert_eq!(v.leading_zeros(), i8x4::new([0, 8, 0, 5]));
+    }
+
+    #[test]
+    fn bit_rotate() {
+        let v = u32x4::splat(1);
+        assert_eq!(v.rotate_bits_left(1), u32x4::splat(2));
+        assert_eq!(v.rotate_bits_left(31), u32x4::splat(1 << 31));
+        assert_eq!(v.rotate_bits_left(32), v);
+        assert_eq!(v.rotate_bits_left(33), u32x4::splat(2));
+        assert_eq!(v.rotate_bits_right(1), u32x4::splat(1 << 31));
+
+        let v = u8x2::new([0b1000_0001, 0b0000_0110]);
+        assert_eq!(
+            v.rotate_bits_right(2),
+            u8x2::new([0b0110_0000, 0b1000_0001])
+        );
+    }
+
+    #[test]
+    fn lane_rotate_in_place() {
+        // The slice methods reached through DerefMut still rotate the lanes, not the bits
+        let mut v = u32x4::new([1, 2, 3, 4]);
+        v.rotate_left(1);
+        assert_eq!(v, u32x4::new([2, 3, 4, 1]));
+        v.rotate_right(2);
+        assert_eq!(v, u32x4::new([4, 1, 2, 3]));
+        v[..].rotate_left(1);
+        assert_eq!(v, u32x4::new([1, 2, 3, 4]));
+        assert_eq!(v.rotate_lanes_left(1), u32x4::new([2, 3, 4, 1]));
+    }
+
+    #[test]
+    fn endianness() {
+        let v = u32x4::new([0x0102_0304, 1, 0, u32::MAX]);
+        assert_eq!(
+            v.swap_bytes(),
+            u32x4::new([0x0403_0201, 0x0100_0000, 0, u32::MAX])
+        );
+        assert_eq!(u32x4::from_be(v.to_be()), v);
+        assert_eq!(u32x4::from_le(v.to_le()), v);
+        assert_eq!(v.to_be()[0].to_ne_bytes(), [1, 2, 3, 4]);
+        assert_eq!(v.to_le()[0].to_ne_bytes(), [4, 3, 2, 1]);
+        assert_eq!(
+            i16x2::new([0x0102, -2]).swap_bytes(),
+            i16x2::new([0x0201, -257])
+        );
+    }
+
+    #[test]
+    fn abs() {
+        let v = i32x4::new([1, -2, i32::MIN, i32::MAX]);
+        assert_eq!(v.abs(), i32x4::new([1, 2, i32::MIN, i32::MAX]));
+
+        let w = wi8x4::new([-1, 0, i8::MIN, -127].map(core::num::Wrapping));
+        assert_eq!(
+            w.abs(),
+            wi8x4::new([1, 0, i8::MIN, 127].map(core::num::Wrapping))
+        );
+    }
+
+    #[test]
+    fn signum() {
+        let v = i16x4::new([-5, 7, 0, i16::MIN]);
+        assert_eq!(v.signum(), i16x4::new([-1, 1, 0, -1]));
+        assert_eq!(v.is_negative().bitmask(), 0b1001);
+    }
+
+    #[test]
+    fn widen() {
+        let v = u16x8::new([0, 1, 2, 3, 40_000, 50_000, 60_000, u16::MAX]);
+        let (lo, hi) = v.widen();
+        assert_eq!(lo, u32x4::new([0, 1, 2, 3]));
+        assert_eq!(hi, u32x4::new([40_000, 50_000, 60_000, 65_535]));
+
+        let v = i8x16::new([
+            0,
+            -1,
+            2,
+            -3,
+            4,
+            -5,
+            6,
+            -7,
+            8,
+            -9,
+            10,
+            -11,
+            12,
+            -13,
+            i8::MIN,
+            i8::MAX,
+        ]);
+        let (lo, hi) = v.widen();
+        assert_eq!(lo, i16x8::new([0, -1, 2, -3, 4, -5, 6, -7]));
+        assert_eq!(hi, i16x8::new([8, -9, 10, -11, 12, -13, -128, 127]));
+    }
+
+    #[test]
+    fn narrow_saturate() {
+        let lo = u32x4::new([0, 65_535, 65_536, 100_000]);
+        let hi = u32x4::new([1, 2, u32::MAX, 70]);
+        assert_eq!(
+            u16x8::narrow_saturate(lo, hi),
+            u16x8::new([0, 65_535, 65_535, 65_535, 1, 2, 65_535, 70])
+        );
+
+        let lo = u16x16::new([300; 16]);
+        let hi = u16x16::new([200; 16]);
+        let packed = u8x32::narrow_saturate(lo, hi);
+        assert_eq!(packed[..16], [255; 16]);
+        assert_eq!(packed[16..], [200; 16]);
+
+        let lo = i32x4::new([-40_000, 40_000, -5, i32::MIN]);
+        let hi = i32x4::new([i32::MAX, 32_767, -32_768, 0]);
+        assert_eq!(
+            i16x8::narrow_saturate(lo, hi),
+            i16x8::new([
+                i16::MIN,
+                i16::MAX,
+                -5,
+                i16::MIN,
+                i16::MAX,
+                32_767,
+                -32_768,
+                0
+            ])
+        );
+
+        let v = i16x8::new([1, -2, 3, -4, 5, -6, i16::MIN, i16::MAX]);
+        let (lo, hi) = v.widen();
+        assert_eq!(i16x8::narrow_saturate(lo, hi), v);
+    }
+
+    #[test]
+    fn shift_by_u32() {
+        let v = i64x2::new([1, -8]);
+        assert_eq!(v << 3u32, i64x2::new([8, -64]));
+        assert_eq!(v >> 2u32, i64x2::new([0, -2]));
+
+        let n: u32 = 4;
+        let b = u8x16::splat(0xf0);
+        assert_eq!(b >> n, u8x16::splat(0x0f));
+        assert_eq!(b << n, u8x16::splat(0));
+
+        let mut a = u16x4::splat(1);
+        a <<= n;
+        assert_eq!(a, u16x4::splat(16));
+        a >>= 2u32;
+        assert_eq!(a, u16x4::splat(4));
+
+        // Unsuffixed literals still work
+        assert_eq!(a << 1, u16x4::splat(8));
+        assert_eq!(u32x4::splat(8) >> 3, u32x4::splat(1));
+    }
+}
@@ -0,0 +1,696 @@
+//! Approximations of elementary functions on the floating point vectors.
+//!
+//! Unlike the methods in the `float` module, these don't call the scalar functions for each lane.
+//! They are computed with plain arithmetic and bit manipulation on whole vectors (range reduction
+//! and a polynomial approximation), which the compiler is able to vectorize. The price for that is
+//! they are not precise to the last bit, the error is documented on each method. They also don't
+//! need the `std` feature.
+
+use core::ops::{Add, Mul};
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+/// The `ln(2)` split into the part with only few bits set (so multiplying by small integers is
+/// exact) and the rest.
+const LN2_HI: f32 = 0.693_359_4;
+const LN2_LO: f32 = -2.121_944_4e-4;
+
+impl<A: Align, B: Repr + Add<Output = B> + Mul<Output = B>, const S: usize> Vector<A, B, S> {
+    /// Evaluates the polynomial with the given coefficients (the highest power first).
+    #[inline(always)]
+    fn poly(self, coeffs: &[B]) -> Self {
+        let mut result = Self::splat(coeffs[0]);
+        for &c in &coeffs[1..] {
+            result = result * self + c;
+        }
+        result
+    }
+}
+
+impl<A: Align, const S: usize> Vector<A, f32, S> {
+    /// Creates `2^n` for each lane.
+    ///
+    /// The lanes of `n` need to be in the range of exponents of normal numbers (`-126..=127`).
+    #[inline(always)]
+    fn pow2(n: Vector<A, i32, S>) -> Self {
+        Self::from_bits(((n + 127) << 23u32).cast())
+    }
+
+    /// Lane-wise approximation of `e^x`.
+    ///
+    /// The maximum relative error is below `5e-7` (few units in the last place) for normal
+    /// results, subnormal ones are off by at most one unit in the last place. Results too large
+    /// to represent are positive infinity, results too small are zero and NaNs stay NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([0.0, 1.0, -1.0, 100.0]).exp();
+    /// assert_eq!(v[0], 1.0);
+    /// assert!((v[1] - 1.0f32.exp()).abs() < 1e-6);
+    /// assert!((v[2] - (-1.0f32).exp()).abs() < 1e-6);
+    /// assert_eq!(v[3], f32::INFINITY);
+    /// ```
+    #[inline]
+    pub fn exp(self) -> Self {
+        // Anything outside of this range overflows or underflows anyway, but keeps the exponents
+        // below in the range of i32 and of the scaling. NaNs pass through both comparisons.
+        let over = Self::splat(89.0);
+        let under = Self::splat(-104.0);
+        let x = self.blend(over, self.gt(over)).blend(under, self.lt(under));
+
+        // Split x = n * ln(2) + r, where |r| <= ln(2) / 2. The magic constant rounds to the
+        // nearest integer without needing std.
+        const MAGIC: f32 = 12_582_912.0;
+        let n = (x * core::f32::consts::LOG2_E + MAGIC) - MAGIC;
+        let r = x - n * LN2_HI - n * LN2_LO;
+
+        let r2 = r * r;
+        let y = r.poly(&[
+            1.987_569_1e-4,
+            1.398_2e-3,
+            8.333_452e-3,
+            4.166_579_6e-2,
+            1.666_666_5e-1,
+            0.5,
+        ]) * r2
+            + r
+            + 1.0;
+
+        // The n may be slightly outside of the exponent range, so scale in two steps.
+        let n = n.cast::<A, i32>();
+        let n1 = n >> 1;
+        let n2 = n - n1;
+        y * Self::pow2(n1) * Self::pow2(n2)
+    }
+
+    /// Lane-wise approximation of the natural logarithm.
+    ///
+    /// The maximum relative error is below `5e-7` (few units in the last place) for all positive
+    /// inputs, including subnormal ones. Like the scalar version, zeros produce negative
+    /// infinity, negative numbers produce NaN, positive infinity stays infinite and NaNs stay
+    /// NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([1.0, core::f32::consts::E, 0.0, -1.0]).ln();
+    /// assert_eq!(v[0], 0.0);
+    /// assert!((v[1] - 1.0).abs() < 1e-6);
+    /// assert_eq!(v[2], f32::NEG_INFINITY);
+    /// assert!(v[3].is_nan());
+    /// ```
+    #[inline]
+    pub fn ln(self) -> Self {
+        const SQRT_HALF: f32 = core::f32::consts::FRAC_1_SQRT_2;
+        let zero = Self::splat(0.0);
+
+        // Normalize the subnormal numbers first, so the exponent can be read from the bits.
+        let subnormal = self.lt(Self::splat(f32::MIN_POSITIVE));
+        let x = self.blend(self * 8_388_608.0, subnormal);
+
+        // Split x = m * 2^e, where m is in [sqrt(1/2), sqrt(2)), and m -= 1.
+        let bits = x.to_bits();
+        let e = (bits >> 23u32).cast::<A, i32>() - 126;
+        let e = e.cast::<A, f32>();
+        let e = e.blend(e - 23.0, subnormal);
+        let m = Self::from_bits((bits & 0x007f_ffff) | 0x3f00_0000);
+        let small = m.lt(Self::splat(SQRT_HALF));
+        let e = e.blend(e - 1.0, small);
+        let m = (m - 1.0).blend(m + m - 1.0, small);
+
+        let m2 = m * m;
+        let y = m.poly(&[
+            7.037_683_6e-2,
+            -1.151_461e-1,
+            1.167_699_9e-1,
+            -1.242_014_1e-1,
+            1.424_932_3e-1,
+            -1.666_805_8e-1,
+            2.000_071_4e-1,
+            -2.499_999_4e-1,
+            3.333_333e-1,
+        ]) * m
+            * m2;
+        let y = y + e * LN2_LO - m2 * 0.5;
+        let result = m + y + e * LN2_HI;
+
+        result
+            .blend(Self::splat(f32::NEG_INFINITY), self.eq(zero))
+            .blend(Self::splat(f32::NAN), self.lt(zero))
+            .blend(self, self.eq(Self::splat(f32::INFINITY)) | self.is_nan())
+    }
+
+    /// Lane-wise approximation of the four quadrant arctangent of `self` (`y`) and `x`.
+    ///
+    /// Like the scalar `y.atan2(x)`, this computes the angle of the point `(x, y)` in radians, in
+    /// the range `[-π, π]`. The maximum absolute error is below `5e-7`.
+    ///
+    /// The special cases follow the scalar version too:
+    ///
+    /// * On the positive `x` axis the result is `0.0` (with the sign of `y`), on the negative
+    ///   one `π` with the sign of `y`. On the `y` axis the result is `±π/2`.
+    /// * At the origin, the signs of the zeros decide. `atan2(±0, +0)` is `±0` and
+    ///   `atan2(±0, -0)` is `±π`.
+    /// * Infinities produce the angle of the corresponding direction (eg. `π/4` if both are
+    ///   positive infinities).
+    /// * If either of the inputs is NaN, the result is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    /// let y = f32x4::new([1.0, 1.0, 0.0, -2.0]);
+    /// let x = f32x4::new([1.0, 0.0, -1.0, 0.0]);
+    /// let v = y.atan2(x);
+    /// assert!((v[0] - FRAC_PI_4).abs() < 1e-6);
+    /// assert!((v[1] - FRAC_PI_2).abs() < 1e-6);
+    /// assert!((v[2] - PI).abs() < 1e-6);
+    /// assert!((v[3] + FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn atan2(self, x: Self) -> Self {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        const TAN_PI_8: f32 = 0.414_213_57;
+        let zero = Self::splat(0.0);
+        let one = Self::splat(1.0);
+
+        // Reduce to the first octant, a = min / max in [0, 1].
+        let ax = x.abs();
+        let ay = self.abs();
+        let steep = ay.gt(ax);
+        let mn = ay.blend(ax, steep);
+        let mx = ax.blend(ay, steep);
+        let a = mn / mx;
+        // Fix the 0 / 0 and inf / inf cases, which are the origin and the diagonal.
+        let a = a
+            .blend(zero, mx.eq(zero))
+            .blend(one, ax.is_infinite() & ay.is_infinite());
+
+        // Further reduce to |t| <= tan(π/8) by atan(a) = π/4 + atan((a - 1) / (a + 1)).
+        let far = a.gt(Self::splat(TAN_PI_8));
+        let t = a.blend((a - 1.0) / (a + 1.0), far);
+        let offset = zero.blend(Self::splat(FRAC_PI_4), far);
+
+        let z = t * t;
+        let r = z.poly(&[
+            8.053_744_5e-2,
+            -1.387_768_6e-1,
+            1.997_771_1e-1,
+            -3.333_295e-1,
+        ]) * z
+            * t
+            + t
+            + offset;
+
+        // Undo the reductions, back to the original octant and quadrant.
+        let r = r.blend(Self::splat(FRAC_PI_2) - r, steep);
+        let r = r.blend(Self::splat(PI) - r, x.is_sign_negative());
+        r.copysign(self).blend(self + x, self.is_nan() | x.is_nan())
+    }
+
+    /// Lane-wise rough approximation of the reciprocal (`1.0 / self`).
+    ///
+    /// This computes the estimate by bit manipulation and refines it by two steps of the
+    /// Newton-Raphson method, which gives relative error below `3e-4` (about 12 valid bits,
+    /// similar to the hardware approximate instructions). Use [`recip`][Vector::recip] if this
+    /// is not enough.
+    ///
+    /// The error holds for inputs with normal reciprocal. Zeros produce infinity with the same
+    /// sign, infinities produce zero and NaNs stay NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([2.0, -4.0, 0.0, f32::INFINITY]).recip_approx();
+    /// assert!((v[0] - 0.5).abs() < 1e-3);
+    /// assert!((v[1] + 0.25).abs() < 1e-3);
+    /// assert_eq!(v[2], f32::INFINITY);
+    /// assert_eq!(v[3], 0.0);
+    /// ```
+    #[inline]
+    pub fn recip_approx(self) -> Self {
+        let x = self.abs();
+        let y = Self::from_bits(x.to_bits().map_lanes(|b| 0x7ef3_11c3u32.wrapping_sub(b)));
+        let y = y * (Self::splat(2.0) - x * y);
+        let y = y * (Self::splat(2.0) - x * y);
+        y.blend(Self::splat(f32::INFINITY), x.eq(Self::splat(0.0)))
+            .blend(Self::splat(0.0), x.eq(Self::splat(f32::INFINITY)))
+            .blend(x, x.is_nan())
+            .copysign(self)
+    }
+
+    /// Lane-wise approximation of the reciprocal (`1.0 / self`).
+    ///
+    /// This is [`recip_approx`][Vector::recip_approx] with one more Newton-Raphson step, which
+    /// brings the relative error below `2e-7` (few units in the last place). It is still likely
+    /// to be faster than division.
+    #[inline]
+    pub fn recip(self) -> Self {
+        let y = self.recip_approx();
+        // The special values are fixed points of the step, except for the ones producing NaN.
+        let refined = y * (Self::splat(2.0) - self * y);
+        refined.blend(y, refined.is_nan())
+    }
+
+    /// Lane-wise rough approximation of the reciprocal square root (`1.0 / self.sqrt()`).
+    ///
+    /// This computes the estimate by bit manipulation and refines it by one step of the
+    /// Newton-Raphson method, which gives relative error below `2e-3`. Use
+    /// [`rsqrt`][Vector::rsqrt] if this is not enough.
+    ///
+    /// The error holds for positive normal inputs. Zeros produce infinity with the same sign,
+    /// positive infinity produces zero and negative numbers and NaNs produce NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x4::new([4.0, 0.25, 0.0, -1.0]).rsqrt_approx();
+    /// assert!((v[0] - 0.5).abs() < 1e-2);
+    /// assert!((v[1] - 2.0).abs() < 1e-2);
+    /// assert_eq!(v[2], f32::INFINITY);
+    /// assert!(v[3].is_nan());
+    /// ```
+    #[inline]
+    pub fn rsqrt_approx(self) -> Self {
+        let zero = Self::splat(0.0);
+        let y = Self::from_bits(
+            self.to_bits()
+                .map_lanes(|b| 0x5f37_5a86u32.wrapping_sub(b >> 1)),
+        );
+        let y = y * (Self::splat(1.5) - self * 0.5 * y * y);
+        y.blend(Self::splat(f32::INFINITY).copysign(self), self.eq(zero))
+            .blend(zero, self.eq(Self::splat(f32::INFINITY)))
+            .blend(Self::splat(f32::NAN), self.lt(zero))
+            .blend(self, self.is_nan())
+    }
+
+    /// Lane-wise approximation of the reciprocal square root (`1.0 / self.sqrt()`).
+    ///
+    /// This is [`rsqrt_approx`][Vector::rsqrt_approx] with one more Newton-Raphson step, which
+    /// brings the relative error below `5e-6`. It is usually faster than the precise square root
+    /// followed by division and good enough for things like normalizing vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// let v = f32x2::new([3.0, 4.0]);
+    /// let len_sq = (v * v).horizontal_sum();
+    /// let normalized = v * f32x2::splat(len_sq).rsqrt();
+    /// assert!((normalized[0] - 0.6).abs() < 1e-5);
+    /// assert!((normalized[1] - 0.8).abs() < 1e-5);
+    /// ```
+    #[inline]
+    pub fn rsqrt(self) -> Self {
+        let y = self.rsqrt_approx();
+        let refined = y * (Self::splat(1.5) - self * 0.5 * y * y);
+        refined.blend(y, refined.is_nan())
+    }
+}
+
+macro_rules! trig {
+    ($($t: ident => $int: ty, [$dp1: expr, $dp2: expr, $dp3: expr], $magic: expr, $big: expr,
+        sin: [$($sin: expr),*], cos: [$($cos: expr),*];)*) => {
+        $(
+            impl<A: Align, const S: usize> Vector<A, $t, S> {
+                /// Computes the sine of `self` shifted by `quadrant * π/2`.
+                #[inline(always)]
+                fn sin_quadrant(self, quadrant: $int) -> Self {
+                    use core::$t::consts::FRAC_2_PI;
+                    // The π/2 split into parts with few enough bits to be multiplied exactly by
+                    // n.
+                    const DP1: $t = $dp1;
+                    const DP2: $t = $dp2;
+                    const DP3: $t = $dp3;
+                    const MAGIC: $t = $magic;
+
+                    // Split x = n * π/2 + r, where |r| <= π/4. Large values are integers already
+                    // and the rounding trick wouldn't work for them.
+                    let t = self * FRAC_2_PI;
+                    let big = t.abs().ge(Self::splat($big));
+                    let n = ((t + MAGIC) - MAGIC).blend(t, big);
+                    let r = self - n * DP1 - n * DP2 - n * DP3;
+                    // For huge inputs the reduction is no longer precise and could end up far
+                    // outside of the range, where the polynomials explode. The bound is a bit
+                    // larger than π/4, to leave the rounding errors of the reduction alone.
+                    // Infinities produce NaN here and pass through.
+                    let bound = Self::splat(1.0);
+                    let r = r.blend(bound, r.gt(bound)).blend(-bound, r.lt(-bound));
+
+                    let r2 = r * r;
+                    // Multiplying by r as the last step keeps the sign of zero.
+                    let sin = (r2.poly(&[$($sin),*]) * r2 + 1.0) * r;
+                    let cos = r2.poly(&[$($cos),*]) * r2 * r2 - r2 * 0.5 + 1.0;
+
+                    // sin, cos, -sin, -cos in the consecutive quadrants.
+                    let q = (n.cast::<A, $int>() & 3) + quadrant;
+                    let result = sin.blend(cos, (q & 1).eq(Vector::splat(1)));
+                    result.blend(-result, (q & 2).eq(Vector::splat(2)))
+                }
+
+                /// Lane-wise approximation of the sine.
+                ///
+                /// The maximum absolute error is below `1e-6` for `f32` inputs in
+                /// `[-8192, 8192]` and below `1e-15` for `f64` inputs in `[-1e6, 1e6]` (the
+                /// range reduction loses precision further out). Larger finite inputs still
+                /// produce values between `-1` and `1`, but they are not precise (the precision
+                /// of the input itself is lower than the period there anyway). Infinities and
+                /// NaNs produce NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// use core::f32::consts::FRAC_PI_2;
+                /// let v = f32x4::new([0.0, FRAC_PI_2, -FRAC_PI_2, f32::INFINITY]).sin();
+                /// assert_eq!(v[0], 0.0);
+                /// assert!((v[1] - 1.0).abs() < 1e-6);
+                /// assert!((v[2] + 1.0).abs() < 1e-6);
+                /// assert!(v[3].is_nan());
+                /// ```
+                #[inline]
+                pub fn sin(self) -> Self {
+                    self.sin_quadrant(0)
+                }
+
+                /// Lane-wise approximation of the cosine.
+                ///
+                /// The precision and handling of large inputs is the same as with
+                /// [`sin`][Vector::sin].
+                ///
+                /// # Examples
+                ///
+                /// ```rust
+                /// # use slipstream::prelude::*;
+                /// use core::f64::consts::PI;
+                /// let v = f64x4::new([0.0, PI, -PI, 1e300]).cos();
+                /// assert_eq!(v[0], 1.0);
+                /// assert!((v[1] + 1.0).abs() < 1e-15);
+                /// assert!((v[2] + 1.0).abs() < 1e-15);
+                /// assert!(v[3].abs() <= 1.0);
+                /// ```
+                #[inline]
+                pub fn cos(self) -> Self {
+                    self.sin_quadrant(1)
+                }
+            }
+        )*
+    };
+}
+
+trig! {
+    f32 => i32, [1.570_312_5, 4.837_513e-4, 7.549_79e-8], 12_582_912.0, 4_194_304.0,
+        sin: [-1.951_529_6e-4, 8.332_161e-3, -1.666_665_5e-1],
+        cos: [2.443_315_7e-5, -1.388_731_6e-3, 4.166_664_6e-2];
+    f64 => i64,
+        [
+            1.570_796_251_296_997,
+            7.549_789_415_861_596e-8,
+            5.390_302_858_158_119e-15
+        ],
+        6_755_399_441_055_744.0, 2_251_799_813_685_248.0,
+        sin: [
+            1.589_623_015_765_465_6e-10,
+            -2.505_074_776_285_780_7e-8,
+            2.755_731_362_138_572_2e-6,
+            -1.984_126_982_958_954e-4,
+            8.333_333_333_322_118e-3,
+            -1.666_666_666_666_663e-1
+        ],
+        cos: [
+            -1.135_853_652_138_768_2e-11,
+            2.087_570_084_197_473e-9,
+            -2.755_731_417_929_674e-7,
+            2.480_158_728_885_170_4e-5,
+            -1.388_888_888_887_305_6e-3,
+            4.166_666_666_666_659_5e-2
+        ];
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use crate::prelude::*;
+
+    fn check(mut input: impl Iterator<Item = f32>, f: fn(f32x8) -> f32x8, s: fn(f32) -> f32) {
+        loop {
+            let mut v = f32x8::default();
+            for i in 0..8 {
+                match input.next() {
+                    Some(x) => v[i] = x,
+                    None => return,
+                }
+            }
+            let result = f(v);
+            for i in 0..8 {
+                let expected = s(v[i]);
+                if expected.is_finite() && expected != 0.0 {
+                    // Subnormal results can't hold the relative precision, allow one ulp there
+                    let err = (result[i] - expected).abs();
+                    let tolerance = (expected.abs() * 5e-7).max(1e-45);
+                    assert!(err <= tolerance, "{}: {} vs {}", v[i], result[i], expected);
+                } else if expected.is_nan() {
+                    assert!(result[i].is_nan(), "{}", v[i]);
+                } else {
+                    assert_eq!(result[i].to_bits(), expected.to_bits(), "{}", v[i]);
+                }
+            }
+        }
+    }
+
+    /// Some interesting values and then a dense sweep over everything.
+    fn inputs(from: f32, to: f32) -> impl Iterator<Item = f32> {
+        const SPECIAL: [f32; 11] = [
+            0.0, -0.0, 1.0, -1.0, 1e-30, 1e-40, -1e-40, 1e30, 88.7, 88.8, -87.4,
+        ];
+        let steps = 100_000;
+        let sweep = (0..=steps).map(move |i| from + (to - from) * (i as f32 / steps as f32));
+        SPECIAL.iter().copied().chain(sweep)
+    }
+
+    #[test]
+    fn exp() {
+        check(inputs(-110.0, 90.0), f32x8::exp, f32::exp);
+        check(inputs(-1.0, 1.0), f32x8::exp, f32::exp);
+        check(
+            [f32::INFINITY, f32::NEG_INFINITY, -1000.0, 1000.0]
+                .iter()
+                .copied(),
+            f32x8::exp,
+            f32::exp,
+        );
+        assert!(f32x4::splat(f32::NAN).exp()[0].is_nan());
+    }
+
+    #[test]
+    fn ln() {
+        check(inputs(0.0, 1e-3), f32x8::ln, f32::ln);
+        check(inputs(0.5, 2.0), f32x8::ln, f32::ln);
+        check(inputs(0.0, 1e6), f32x8::ln, f32::ln);
+        // Over the whole range of exponents, including subnormals.
+        let exps = (-149..128).map(|e| 2.0f32.powi(e) * 1.3);
+        check(exps, f32x8::ln, f32::ln);
+        check(
+            [f32::INFINITY, f32::MAX, f32::MIN_POSITIVE, 1e-45]
+                .iter()
+                .copied(),
+            f32x8::ln,
+            f32::ln,
+        );
+        let v = f32x4::new([-1.0, f32::NEG_INFINITY, f32::NAN, -0.0]).ln();
+        assert!(v[0].is_nan());
+        assert!(v[1].is_nan());
+        assert!(v[2].is_nan());
+        assert_eq!(v[3], f32::NEG_INFINITY);
+    }
+
+    fn check_abs(f: fn(f32x8) -> f32x8, s: fn(f32) -> f32, from: f32, to: f32, tolerance: f32) {
+        let steps = 100_000;
+        for i in 0..steps / 8 {
+            let mut v = f32x8::default();
+            for j in 0..8 {
+                v[j] = from + (to - from) * ((i * 8 + j) as f32 / steps as f32);
+            }
+            let result = f(v);
+            for j in 0..8 {
+                let err = (result[j] - s(v[j])).abs();
+                assert!(err < tolerance, "{}: {} vs {}", v[j], result[j], s(v[j]));
+            }
+        }
+    }
+
+    #[test]
+    fn sin_cos() {
+        use core::f32::consts::PI;
+        check_abs(f32x8::sin, f32::sin, -2.0 * PI, 2.0 * PI, 1e-6);
+        check_abs(f32x8::cos, f32::cos, -2.0 * PI, 2.0 * PI, 1e-6);
+        check_abs(f32x8::sin, f32::sin, -8192.0, 8192.0, 1e-6);
+        check_abs(f32x8::cos, f32::cos, -8192.0, 8192.0, 1e-6);
+
+        let big = f32x8::new([1e5, -1e7, 3e9, -1e20, 1e38, f32::MAX, f32::MIN, 4194305.0]);
+        for v in [big.sin(), big.cos()] {
+            for x in v {
+                assert!(x.abs() <= 1.0, "{}", x);
+            }
+        }
+        let special = f32x4::new([f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -0.0]);
+        let sin = special.sin();
+        assert!(sin[0].is_nan() && sin[1].is_nan() && sin[2].is_nan());
+        assert!(sin[3] == 0.0 && sin[3].is_sign_negative());
+        let cos = special.cos();
+        assert!(cos[0].is_nan() && cos[1].is_nan() && cos[2].is_nan());
+        assert_eq!(cos[3], 1.0);
+    }
+
+    #[test]
+    fn sin_cos_f64() {
+        use core::f64::consts::PI;
+        let steps = 100_000;
+        for &(from, to) in &[(-2.0 * PI, 2.0 * PI), (-1e6, 1e6)] {
+            for i in 0..steps / 4 {
+                let v =
+                    f64x4::from_fn(|j| from + (to - from) * ((i * 4 + j) as f64 / steps as f64));
+                let (sin, cos) = (v.sin(), v.cos());
+                for j in 0..4 {
+                    assert!((sin[j] - v[j].sin()).abs() < 1e-15, "{}: {}", v[j], sin[j]);
+                    assert!((cos[j] - v[j].cos()).abs() < 1e-15, "{}: {}", v[j], cos[j]);
+                }
+            }
+        }
+
+        let big = f64x4::new([1e17, -3e20, f64::MAX, f64::MIN]);
+        for v in [big.sin(), big.cos()] {
+            for x in v {
+                assert!(x.abs() <= 1.0, "{}", x);
+            }
+        }
+        let special = f64x4::new([f64::INFINITY, f64::NEG_INFINITY, f64::NAN, -0.0]);
+        let sin = special.sin();
+        assert!(sin[0].is_nan() && sin[1].is_nan() && sin[2].is_nan());
+        assert!(sin[3] == 0.0 && sin[3].is_sign_negative());
+        let cos = special.cos();
+        assert!(cos[0].is_nan() && cos[1].is_nan() && cos[2].is_nan());
+        assert_eq!(cos[3], 1.0);
+    }
+
+    #[test]
+    fn atan2() {
+        use core::f32::consts::PI;
+        // Points around circles of different radii
+        let steps = 100_000;
+        for &radius in &[1.0, 1e-20, 3e20, 0.5] {
+            for i in 0..steps / 8 {
+                let angle =
+                    f32x8::from_fn(|j| -PI + 2.0 * PI * ((i * 8 + j) as f32 / steps as f32));
+                let mut x = f32x8::default();
+                let mut y = f32x8::default();
+                for j in 0..8 {
+                    x[j] = angle[j].cos() * radius;
+                    y[j] = angle[j].sin() * radius;
+                }
+                let result = y.atan2(x);
+                for j in 0..8 {
+                    let expected = y[j].atan2(x[j]);
+                    let err = (result[j] - expected).abs();
+                    assert!(
+                        err < 5e-7,
+                        "{}, {}: {} vs {}",
+                        y[j],
+                        x[j],
+                        result[j],
+                        expected
+                    );
+                }
+            }
+        }
+
+        // The axes, origin, infinities and NaNs
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            1e-40,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        for &y in &values {
+            for &x in &values {
+                let result = f32x4::splat(y).atan2(f32x4::splat(x))[0];
+                let expected = y.atan2(x);
+                if expected.is_nan() {
+                    assert!(result.is_nan(), "{}, {}: {}", y, x, result);
+                } else {
+                    assert!((result - expected).abs() < 5e-7, "{}, {}: {}", y, x, result);
+                    assert_eq!(
+                        result.is_sign_negative(),
+                        expected.is_sign_negative(),
+                        "{}, {}",
+                        y,
+                        x
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_rel(f: fn(f32x8) -> f32x8, s: fn(f32) -> f32, tolerance: f32) {
+        // Logarithmic sweep over most of the normal range, both signs.
+        let steps = 100_000;
+        for i in 0..steps / 8 {
+            let mut v = f32x8::default();
+            for j in 0..8 {
+                let e = -120.0 + 240.0 * ((i * 8 + j) as f32 / steps as f32);
+                v[j] = 2.0f32.powf(e);
+            }
+            for v in [v, -v] {
+                let result = f(v);
+                for j in 0..8 {
+                    let expected = s(v[j]);
+                    if expected.is_nan() {
+                        assert!(result[j].is_nan());
+                    } else {
+                        let err = ((result[j] - expected) / expected).abs();
+                        assert!(err < tolerance, "{}: {} vs {}", v[j], result[j], expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn recip() {
+        check_rel(f32x8::recip_approx, f32::recip, 3e-4);
+        check_rel(f32x8::recip, f32::recip, 2e-7);
+        let special = f32x4::new([0.0, -0.0, f32::NEG_INFINITY, f32::NAN]);
+        for v in [special.recip(), special.recip_approx()] {
+            assert_eq!(v[0], f32::INFINITY);
+            assert_eq!(v[1], f32::NEG_INFINITY);
+            assert!(v[2] == 0.0 && v[2].is_sign_negative());
+            assert!(v[3].is_nan());
+        }
+    }
+
+    #[test]
+    fn rsqrt() {
+        let exact = |x: f32| 1.0 / x.sqrt();
+        check_rel(f32x8::rsqrt_approx, exact, 2e-3);
+        check_rel(f32x8::rsqrt, exact, 5e-6);
+        let special = f32x4::new([0.0, -0.0, f32::INFINITY, f32::NAN]);
+        for v in [special.rsqrt(), special.rsqrt_approx()] {
+            assert_eq!(v[0], f32::INFINITY);
+            assert_eq!(v[1], f32::NEG_INFINITY);
+            assert_eq!(v[2], 0.0);
+            assert!(v[3].is_nan());
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Support for the [`num_traits`] crate.
+
+use core::ops::{Add, Mul};
+
+use num_traits::{One, Zero};
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+impl<A, B, const S: usize> Zero for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + Zero + Add<Output = B>,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::splat(B::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.data.iter().all(B::is_zero)
+    }
+}
+
+impl<A, B, const S: usize> One for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + Mul<Output = B>,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::splat(B::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    use num_traits::{One, Zero};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(u32x4::zero() + u32x4::one(), u32x4::splat(1));
+        assert_eq!(f32x4::one(), f32x4::splat(1.0));
+        assert_eq!(wi16x8::zero(), wi16x8::splat(Wrapping(0)));
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(u32x4::zero().is_zero());
+        assert!(!u32x4::new([0, 0, 1, 0]).is_zero());
+        assert!(!u32x4::one().is_zero());
+        assert!(f32x2::new([0.0, -0.0]).is_zero());
+    }
+
+    #[test]
+    fn generic_sum() {
+        fn sum<T: Zero + Copy>(data: &[T]) -> T {
+            data.iter().fold(T::zero(), |acc, v| acc + *v)
+        }
+
+        let data = [u32x4::new([1, 2, 3, 4]), u32x4::new([5, 6, 7, 8])];
+        assert_eq!(sum(&data), u32x4::new([6, 8, 10, 12]));
+        assert_eq!(sum(&[1u32, 2, 3]), 6);
+    }
+}
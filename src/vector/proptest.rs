@@ -0,0 +1,105 @@
+//! Support for property testing with the [`proptest`] crate.
+
+use core::fmt::Debug;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::array::{self, UniformArrayStrategy};
+use proptest::strategy::{Map, Strategy};
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+impl<A, B, const S: usize> Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + Debug,
+{
+    /// A `proptest` strategy generating vectors with each lane generated by the given strategy.
+    ///
+    /// The [`Arbitrary`] implementation uses the default strategy of the base type. This allows
+    /// using a different one. This is useful for the floating point vectors, as by default
+    /// `proptest` generates only finite values. To get the special values (NaNs and infinities)
+    /// too, use the `ANY` strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slipstream::prelude::*;
+    /// use proptest::prelude::*;
+    ///
+    /// proptest! {
+    ///     fn abs_positive(v in f32x4::strategy(proptest::num::f32::ANY)) {
+    ///         prop_assert!(!v.abs().is_sign_negative().any());
+    ///     }
+    /// }
+    /// # abs_positive();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn strategy<T>(lanes: T) -> Map<UniformArrayStrategy<T, [B; S]>, fn([B; S]) -> Self>
+    where
+        T: Strategy<Value = B>,
+    {
+        array::uniform(lanes).prop_map(Self::from)
+    }
+}
+
+/// Generates each lane independently by the strategy of the base type.
+///
+/// Note that for the floating point types this generates only finite values. See
+/// [`Vector::strategy`] for generating the special values too.
+impl<A, B, const S: usize> Arbitrary for Vector<A, B, S>
+where
+    A: Align + 'static,
+    B: Repr + Arbitrary,
+{
+    type Parameters = B::Parameters;
+    type Strategy = Map<UniformArrayStrategy<B::Strategy, [B; S]>, fn([B; S]) -> Self>;
+
+    fn arbitrary_with(args: B::Parameters) -> Self::Strategy {
+        Self::strategy(B::arbitrary_with(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bits_roundtrip(v in f32x4::strategy(proptest::num::f32::ANY)) {
+            prop_assert_eq!(f32x4::from_bits(v.to_bits()).to_bits(), v.to_bits());
+        }
+
+        #[test]
+        fn bits_roundtrip_finite(v: f64x2) {
+            prop_assert!(v.is_finite().all());
+            prop_assert_eq!(f64x2::from_bits(v.to_bits()), v);
+        }
+
+        #[test]
+        fn split_concat(v: u16x16) {
+            let (lo, hi) = v.split_halves();
+            prop_assert_eq!(u16x16::concat(lo, hi), v);
+        }
+    }
+
+    #[test]
+    fn special_floats() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = f64x4::strategy(proptest::num::f64::ANY);
+        let mut nan = false;
+        let mut inf = false;
+        for _ in 0..1000 {
+            let v = strategy.new_tree(&mut runner).unwrap().current();
+            nan |= v.is_nan().any();
+            inf |= v.is_infinite().any();
+        }
+        assert!(nan && inf);
+    }
+}
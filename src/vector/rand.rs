@@ -0,0 +1,52 @@
+//! Support for generating random vectors with the [`rand`] crate.
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+/// Generates each lane independently by the [`Standard`] distribution of the base type.
+///
+/// Therefore, the floating point vectors get their lanes from the `[0, 1)` interval.
+impl<A, B, const S: usize> Distribution<Vector<A, B, S>> for Standard
+where
+    A: Align,
+    B: Repr,
+    Standard: Distribution<B>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector<A, B, S> {
+        Vector::from_fn(|_| rng.gen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn random_ints() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let a: u32x4 = rng.gen();
+        let b: u32x4 = rng.gen();
+        assert_ne!(a, b);
+        // Lanes are independent, not a splat
+        assert_ne!(a, u32x4::splat(a[0]));
+    }
+
+    #[test]
+    fn random_floats() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let v: f64x4 = rng.gen();
+            assert!(v.ge(f64x4::splat(0.0)).all());
+            assert!(v.lt(f64x4::splat(1.0)).all());
+        }
+        assert_ne!(rng.gen::<f32x8>(), rng.gen::<f32x8>());
+    }
+}
@@ -0,0 +1,112 @@
+//! Support for the [`serde`] crate.
+//!
+//! The vectors are serialized as fixed-sized arrays (tuples in the serde data model) of their
+//! lanes.
+
+use core::fmt::{Formatter, Result as FmtResult};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use super::align::Align;
+use super::Vector;
+use crate::inner::Repr;
+
+impl<A, B, const S: usize> Serialize for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + Serialize,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut tuple = serializer.serialize_tuple(S)?;
+        for lane in &self.data {
+            tuple.serialize_element(lane)?;
+        }
+        tuple.end()
+    }
+}
+
+struct VectorVisitor<A: Align, B: Repr, const S: usize>(PhantomData<Vector<A, B, S>>);
+
+impl<'de, A, B, const S: usize> Visitor<'de> for VectorVisitor<A, B, S>
+where
+    A: Align,
+    B: Repr + Deserialize<'de>,
+{
+    type Value = Vector<A, B, S>;
+
+    fn expecting(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(fmt, "an array of {} lanes", S)
+    }
+
+    fn visit_seq<Acc: SeqAccess<'de>>(self, mut seq: Acc) -> Result<Self::Value, Acc::Error> {
+        let mut result = MaybeUninit::<Vector<A, B, S>>::uninit();
+        for i in 0..S {
+            // Note: On error, we simply forget the already read lanes. They are Copy, so nothing
+            // leaks.
+            let lane = seq
+                .next_element()?
+                .ok_or_else(|| Acc::Error::invalid_length(i, &self))?;
+            unsafe {
+                ptr::write(result.as_mut_ptr().cast::<B>().add(i), lane);
+            }
+        }
+        if seq.next_element::<B>()?.is_some() {
+            return Err(Acc::Error::invalid_length(S + 1, &self));
+        }
+        Ok(unsafe { result.assume_init() })
+    }
+}
+
+impl<'de, A, B, const S: usize> Deserialize<'de> for Vector<A, B, S>
+where
+    A: Align,
+    B: Repr + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(S, VectorVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let v = i32x4::new([1, -2, 3, -4]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1,-2,3,-4]");
+        let back: i32x4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn json_wrong_length() {
+        assert!(serde_json::from_str::<i32x4>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<i32x4>("[1,2,3,4,5]").is_err());
+        assert!(serde_json::from_str::<i32x4>("[]").is_err());
+    }
+
+    #[test]
+    fn json_mask() {
+        let m = u32x4::new([1, 2, 3, 4]).lt(u32x4::splat(3));
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[true,true,false,false]");
+        let back: m32x4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(m, back);
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let v = f64x2::new([1.5, -0.25]);
+        let bytes = bincode::serialize(&v).unwrap();
+        // No length prefix, it is a fixed-sized array
+        assert_eq!(bytes.len(), 16);
+        let back: f64x2 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(v, back);
+    }
+}